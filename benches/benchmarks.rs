@@ -1,3 +1,6 @@
+// In-place ops return `()`, they are wrapped in `black_box` to keep the call sites uniform.
+#![allow(clippy::unit_arg)]
+
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
 use tensor::{Tensor, TryCast};
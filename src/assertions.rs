@@ -1,4 +1,5 @@
 use crate::Tensor;
+use crate::num::Integer;
 
 /// Condition: Type `T` is not `ZST`.
 pub(crate) const fn assert_not_zst<T>() {
@@ -26,10 +27,72 @@ pub(crate) const fn assert_same_size(data_len: usize, dims_size: usize) {
     )
 }
 
+/// Condition: The axis is one of the `R` dimensions.
+pub(crate) const fn assert_valid_axis<const R: usize>(axis: usize) {
+    assert!(axis < R, "Axis out of bounds");
+}
+
+/// Condition: The rank `N` of a reduction's result is one less than the rank `R`.
+pub(crate) const fn assert_reduced_rank<const R: usize, const N: usize>() {
+    assert!(
+        N + 1 == R,
+        "Invalid rank: result's rank must be one less than the tensor's rank"
+    );
+}
+
 /// Condition: Tensors of rank `R` must have the same value in each dimension.
-pub(crate) const fn assert_same_shape<T, const R: usize>(a: &Tensor<T, R>, b: &Tensor<T, R>) {
+pub(crate) const fn assert_same_shape<T, U, const R: usize>(a: &Tensor<T, R>, b: &Tensor<U, R>) {
     assert!(
         a.metadata.cmp_dims_eq(&b.metadata),
         "Tensors must have the same shape"
     );
 }
+
+/// Condition: No divisor is `0`.
+///
+/// Checked before writing, to not leave a partially written output buffer behind.
+pub(crate) fn assert_non_zero_divisors<T, const R: usize>(divisors: &Tensor<T, R>)
+where
+    T: Integer,
+{
+    assert!(divisors.iter().all(|d| *d != T::ZERO), "Division by zero");
+}
+
+/// Condition: Matrices of row vectors have vectors of the same length.
+pub(crate) const fn assert_same_row_len<T, U>(a: &Tensor<T, 2>, b: &Tensor<U, 2>) {
+    assert!(
+        a.metadata.dims()[1] == b.metadata.dims()[1],
+        "Vectors must have the same length"
+    );
+}
+
+/// Condition: The rank `N` of the right-hand side of a linear system with matrices of rank `R`
+/// is `R - 1` (vectors) or `R` (matrices).
+pub(crate) const fn assert_right_hand_rank<const R: usize, const N: usize>() {
+    assert!(
+        N + 1 == R || N == R,
+        "Invalid rank: right-hand side's rank must be equal to or one less than the matrix's rank"
+    );
+}
+
+/// Condition: The rank `M` of a product's result is the sum of the ranks `R` and `N` of the
+/// factors, less the `2 * K` contracted axes.
+pub(crate) const fn assert_product_rank<
+    const R: usize,
+    const N: usize,
+    const K: usize,
+    const M: usize,
+>() {
+    assert!(
+        R + N == M + 2 * K,
+        "Invalid rank: result's rank must be the sum of the tensors' ranks less the contracted axes"
+    );
+}
+
+/// Condition: Tensors of rank `R` have matrices in their last two dimensions.
+pub(crate) const fn assert_matrix_rank<const R: usize>() {
+    assert!(
+        R >= 2,
+        "Invalid rank: tensor must have at least two dimensions"
+    );
+}
@@ -83,7 +83,7 @@ mod casting_tests {
 
     #[test]
     fn test_tensor_cast_precision_loss() {
-        let tensor = Tensor::<f32, 2>::new_set([2, 2], 3.14);
+        let tensor = Tensor::<f32, 2>::new_set([2, 2], 3.25);
         let result: Result<Tensor<i32, 2>, CastError> = tensor.try_cast();
         assert_eq!(result.unwrap_err(), CastError::PrecisionLoss);
     }
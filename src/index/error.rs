@@ -0,0 +1,13 @@
+/// Error type for indexing operations.
+/// This enum is used to represent the different types of errors that can occur during indexing.
+/// The following errors are defined:
+/// - `OutOfBounds`: An index along `axis` is greater than or equal to the length `len` of the
+///   axis.
+#[derive(Debug, PartialEq)]
+pub enum IndexError {
+    OutOfBounds {
+        axis: usize,
+        index: usize,
+        len: usize,
+    },
+}
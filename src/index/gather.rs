@@ -0,0 +1,636 @@
+use core::hint::unreachable_unchecked;
+use core::ops::Add;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::{assert_same_shape, assert_valid_axis};
+use crate::index::IndexError;
+use crate::metadata::TensorMetadata;
+
+/// Panics with the axis, index and length reported by `error`.
+fn out_of_bounds(error: IndexError) -> ! {
+    let IndexError::OutOfBounds { axis, index, len } = error;
+    panic!("Index out of bounds: index {index} along axis {axis} of length {len}")
+}
+
+/// Checks that `n` values of `indices` are within the length `len` of `axis`.
+#[inline(always)]
+unsafe fn check_indices(
+    n: usize,
+    indices: *const usize,
+    axis: usize,
+    len: usize,
+) -> Result<(), IndexError> {
+    let mut i = 0;
+    while i < n {
+        let index = unsafe { *indices.add(i) };
+        if index >= len {
+            return Err(IndexError::OutOfBounds { axis, index, len });
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Calls `f` with the position of each value in `indices` and the offset it addresses along
+/// `axis` in a buffer with `strides`, where the other coordinates are the coordinates of the
+/// value in `indices`.
+///
+/// Indices must be checked before calling this function.
+#[inline(always)]
+unsafe fn for_each_offset<const R: usize, F>(
+    axis: usize,
+    indices: &Tensor<usize, R>,
+    strides: &[usize],
+    mut f: F,
+) where
+    F: FnMut(usize, usize),
+{
+    let len = indices.metadata.size();
+    let dims = indices.metadata.shape();
+    let idx = indices.data.as_ptr();
+    let axis_stride = strides[axis];
+
+    let mut coords = [0; R];
+    let mut base = 0;
+    let mut pos = 0;
+
+    while pos < len {
+        f(pos, base + unsafe { *idx.add(pos) } * axis_stride);
+
+        let mut d = R;
+        while d != 0 {
+            d -= 1;
+            if coords[d] + 1 < dims[d] {
+                coords[d] += 1;
+                if d != axis {
+                    base += strides[d];
+                }
+                break;
+            }
+            if d != axis {
+                base -= coords[d] * strides[d];
+            }
+            coords[d] = 0;
+        }
+
+        pos += 1;
+    }
+}
+
+/// Condition: The dimensions of `indices` are not greater than the dimensions of `dims`,
+/// except along `axis`.
+fn assert_fits_except_axis(axis: usize, indices: &[usize], dims: &[usize]) {
+    let mut d = 0;
+    while d < dims.len() {
+        assert!(
+            d == axis || indices[d] <= dims[d],
+            "Indices must not exceed the tensor's dimensions except along axis"
+        );
+        d += 1;
+    }
+}
+
+/// Condition: The dimensions of `indices` match the dimensions of `dims`, except along `axis`.
+fn assert_same_except_axis(axis: usize, indices: &[usize], dims: &[usize]) {
+    let mut d = 0;
+    while d < dims.len() {
+        assert!(
+            d == axis || indices[d] == dims[d],
+            "Indices must have the same shape as the tensor except along axis"
+        );
+        d += 1;
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R> {
+    /// Selects the slices of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>`, in the order of `indices`.
+    ///
+    /// The dimensions of the result are the dimensions of `self`, except along `axis` where it
+    /// is the size of `indices`.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is out of bounds or any of the indices are out of
+    /// bounds. Use [`Tensor::try_index_select`] to handle out of bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let embeddings = Tensor::from_slice([3, 2], &[0, 0, 1, 1, 2, 2]);
+    /// let tokens = Tensor::from_slice([4], &[2, 0, 2, 1]);
+    ///
+    /// let result = embeddings.index_select(0, &tokens);
+    ///
+    /// assert_eq!(result.shape(), &[4, 2]);
+    /// assert_eq!(result.as_slice(), &[2, 2, 0, 0, 2, 2, 1, 1]);
+    /// ```
+    pub fn index_select(&self, axis: usize, indices: &Tensor<usize, 1>) -> Self
+    where
+        T: Copy,
+    {
+        match self.try_index_select(axis, indices) {
+            Ok(instance) => instance,
+            Err(error) => out_of_bounds(error),
+        }
+    }
+
+    /// Selects the slices of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>`, in the order of `indices`.
+    ///
+    /// Returns `IndexError::OutOfBounds` with the first index that is out of bounds.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is out of bounds.
+    pub fn try_index_select(
+        &self,
+        axis: usize,
+        indices: &Tensor<usize, 1>,
+    ) -> Result<Self, IndexError>
+    where
+        T: Copy,
+    {
+        assert_valid_axis::<R>(axis);
+
+        let count = indices.metadata.size();
+        let idx = indices.data.as_ptr();
+
+        let mut dims = self.metadata.dims();
+        let axis_len = dims[axis];
+
+        unsafe { check_indices(count, idx, axis, axis_len)? };
+
+        dims[axis] = count;
+        let metadata = TensorMetadata::new(dims);
+
+        // Contiguous block of values per index.
+        let (outer, _, inner) = self.metadata.split_at_axis(axis);
+
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = match output.layout_of(metadata.size(), OnError::Panic) {
+                Ok(layout) => layout,
+                Err(_) => unreachable_unchecked(),
+            };
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            let mut r = output.as_ptr_mut();
+
+            let mut o = 0;
+            while o < outer {
+                let src = a.add(o * axis_len * inner);
+                let mut j = 0;
+                while j < count {
+                    r.copy_from_nonoverlapping(src.add(*idx.add(j) * inner), inner);
+                    r = r.add(inner);
+                    j += 1;
+                }
+                o += 1;
+            }
+
+            Ok(Tensor {
+                metadata,
+                data: output,
+            })
+        }
+    }
+
+    /// Takes the values of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>` with the shape of `indices`.
+    ///
+    /// For rank `2` and `axis` `1`, the result is `result[i][j] = self[i][indices[i][j]]`.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `self` do not match, except along `axis`.
+    /// - any of the indices are out of bounds. Use [`Tensor::try_take_along_axis`] to handle
+    ///   out of bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let scores = Tensor::from_slice([2, 3], &[10, 20, 30, 40, 50, 60]);
+    /// let labels = Tensor::from_slice([2, 1], &[2, 0]);
+    ///
+    /// let result = scores.take_along_axis(1, &labels);
+    ///
+    /// assert_eq!(result.shape(), &[2, 1]);
+    /// assert_eq!(result.as_slice(), &[30, 40]);
+    /// ```
+    pub fn take_along_axis(&self, axis: usize, indices: &Tensor<usize, R>) -> Self
+    where
+        T: Copy,
+    {
+        match self.try_take_along_axis(axis, indices) {
+            Ok(instance) => instance,
+            Err(error) => out_of_bounds(error),
+        }
+    }
+
+    /// Takes the values of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>` with the shape of `indices`.
+    ///
+    /// Returns `IndexError::OutOfBounds` with the first index that is out of bounds.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `self` do not match, except along `axis`.
+    pub fn try_take_along_axis(
+        &self,
+        axis: usize,
+        indices: &Tensor<usize, R>,
+    ) -> Result<Self, IndexError>
+    where
+        T: Copy,
+    {
+        assert_valid_axis::<R>(axis);
+        assert_same_except_axis(axis, indices.shape(), self.shape());
+        self.gather_unchecked_shape(axis, indices)
+    }
+
+    /// Gathers the values of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>` with the shape of `indices`.
+    ///
+    /// Unlike [`Tensor::take_along_axis`], the dimensions of `indices` may be smaller than the
+    /// dimensions of `self`, in which case only the leading part of each dimension is gathered.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    /// - any of the indices are out of bounds. Use [`Tensor::try_gather`] to handle out of
+    ///   bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+    /// let indices = Tensor::from_slice([1, 3], &[1, 0, 1]);
+    ///
+    /// let result = tensor.gather(0, &indices);
+    ///
+    /// assert_eq!(result.as_slice(), &[4, 2, 6]);
+    /// ```
+    pub fn gather(&self, axis: usize, indices: &Tensor<usize, R>) -> Self
+    where
+        T: Copy,
+    {
+        match self.try_gather(axis, indices) {
+            Ok(instance) => instance,
+            Err(error) => out_of_bounds(error),
+        }
+    }
+
+    /// Gathers the values of `self` at `indices` along `axis` and returns them as new
+    /// `Tensor<T, R>` with the shape of `indices`.
+    ///
+    /// Returns `IndexError::OutOfBounds` with the first index that is out of bounds.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    pub fn try_gather(&self, axis: usize, indices: &Tensor<usize, R>) -> Result<Self, IndexError>
+    where
+        T: Copy,
+    {
+        assert_valid_axis::<R>(axis);
+        assert_fits_except_axis(axis, indices.shape(), self.shape());
+        self.gather_unchecked_shape(axis, indices)
+    }
+
+    /// Gathers with shape of `indices` already checked.
+    fn gather_unchecked_shape(
+        &self,
+        axis: usize,
+        indices: &Tensor<usize, R>,
+    ) -> Result<Self, IndexError>
+    where
+        T: Copy,
+    {
+        let len = indices.metadata.size();
+        let axis_len = self.shape()[axis];
+
+        unsafe { check_indices(len, indices.data.as_ptr(), axis, axis_len)? };
+
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            let r = output.as_ptr_mut();
+
+            for_each_offset(axis, indices, self.metadata.strides(), |pos, offset| {
+                r.add(pos).write(*a.add(offset))
+            });
+
+            Ok(Tensor {
+                metadata: indices.metadata,
+                data: output,
+            })
+        }
+    }
+
+    /// Writes the values of `source` in-place into `self` at `indices` along `axis`.
+    ///
+    /// For rank `2` and `axis` `1`, the operation is `self[i][indices[i][j]] = source[i][j]`.
+    /// If multiple indices address the same value, the last one in row-major order is written.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `source` do not match.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    /// - any of the indices are out of bounds. Use [`Tensor::try_scatter`] to handle out of
+    ///   bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 3], 0);
+    /// let indices = Tensor::from_slice([2, 1], &[2, 0]);
+    /// let source = Tensor::from_slice([2, 1], &[7, 9]);
+    ///
+    /// tensor.scatter(1, &indices, &source);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[0, 0, 7, 9, 0, 0]);
+    /// ```
+    pub fn scatter(&mut self, axis: usize, indices: &Tensor<usize, R>, source: &Self)
+    where
+        T: Copy,
+    {
+        if let Err(error) = self.try_scatter(axis, indices, source) {
+            out_of_bounds(error)
+        }
+    }
+
+    /// Writes the values of `source` in-place into `self` at `indices` along `axis`.
+    ///
+    /// Returns `IndexError::OutOfBounds` with the first index that is out of bounds, in which
+    /// case `self` is left unchanged.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `source` do not match.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    pub fn try_scatter(
+        &mut self,
+        axis: usize,
+        indices: &Tensor<usize, R>,
+        source: &Self,
+    ) -> Result<(), IndexError>
+    where
+        T: Copy,
+    {
+        self.scatter_with(axis, indices, source, |_, s| s)
+    }
+
+    /// Adds the values of `source` in-place to `self` at `indices` along `axis`.
+    ///
+    /// For rank `2` and `axis` `1`, the operation is `self[i][indices[i][j]] += source[i][j]`.
+    /// Values addressed by multiple indices accumulate all of them.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `source` do not match.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    /// - any of the indices are out of bounds. Use [`Tensor::try_scatter_add`] to handle out of
+    ///   bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut counts = Tensor::new_set([3], 0);
+    /// let labels = Tensor::from_slice([4], &[2, 0, 2, 2]);
+    /// let ones = Tensor::new_set([4], 1);
+    ///
+    /// counts.scatter_add(0, &labels, &ones);
+    ///
+    /// assert_eq!(counts.as_slice(), &[1, 0, 3]);
+    /// ```
+    pub fn scatter_add(&mut self, axis: usize, indices: &Tensor<usize, R>, source: &Self)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        if let Err(error) = self.try_scatter_add(axis, indices, source) {
+            out_of_bounds(error)
+        }
+    }
+
+    /// Adds the values of `source` in-place to `self` at `indices` along `axis`.
+    ///
+    /// Returns `IndexError::OutOfBounds` with the first index that is out of bounds, in which
+    /// case `self` is left unchanged.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - the dimensions of `indices` and `source` do not match.
+    /// - any dimension of `indices` is greater than the dimension of `self`, except along
+    ///   `axis`.
+    pub fn try_scatter_add(
+        &mut self,
+        axis: usize,
+        indices: &Tensor<usize, R>,
+        source: &Self,
+    ) -> Result<(), IndexError>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.scatter_with(axis, indices, source, |a, s| a + s)
+    }
+
+    /// Combines the values of `source` with the values of `self` at `indices` along `axis`.
+    fn scatter_with<F>(
+        &mut self,
+        axis: usize,
+        indices: &Tensor<usize, R>,
+        source: &Self,
+        f: F,
+    ) -> Result<(), IndexError>
+    where
+        T: Copy,
+        F: Fn(T, T) -> T,
+    {
+        assert_valid_axis::<R>(axis);
+        assert_same_shape(indices, source);
+        assert_fits_except_axis(axis, indices.shape(), self.shape());
+
+        let len = indices.metadata.size();
+        let axis_len = self.shape()[axis];
+
+        // All indices are checked first to leave `self` unchanged on error.
+        unsafe { check_indices(len, indices.data.as_ptr(), axis, axis_len)? };
+
+        let a = self.data.as_ptr_mut();
+        let s = source.data.as_ptr();
+
+        unsafe {
+            for_each_offset(axis, indices, self.metadata.strides(), |pos, offset| {
+                let r = a.add(offset);
+                r.write(f(*r, *s.add(pos)))
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod gather_tests {
+    use super::*;
+
+    #[test]
+    fn test_index_select() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+        let indices = Tensor::from_slice([4], &[2, 0, 0, 1]);
+
+        let result = tensor.index_select(1, &indices);
+
+        assert_eq!(result.shape(), &[2, 4]);
+        assert_eq!(result.as_slice(), &[3, 1, 1, 2, 6, 4, 4, 5]);
+    }
+
+    #[test]
+    fn test_try_index_select_out_of_bounds() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+        let indices = Tensor::from_slice([3], &[1, 2, 5]);
+
+        let result = tensor.try_index_select(0, &indices);
+
+        assert_eq!(
+            result.unwrap_err(),
+            IndexError::OutOfBounds {
+                axis: 0,
+                index: 2,
+                len: 2
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 3 along axis 1 of length 3")]
+    fn test_index_select_out_of_bounds() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+        let indices = Tensor::from_slice([1], &[3]);
+
+        let _ = tensor.index_select(1, &indices);
+    }
+
+    #[test]
+    fn test_take_along_axis() {
+        let tensor = Tensor::from_slice([2, 2, 2], &[1, 2, 3, 4, 5, 6, 7, 8]);
+        let indices = Tensor::from_slice([2, 1, 2], &[1, 0, 0, 1]);
+
+        let result = tensor.take_along_axis(1, &indices);
+
+        assert_eq!(result.shape(), &[2, 1, 2]);
+        assert_eq!(result.as_slice(), &[3, 2, 5, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_take_along_axis_invalid_shape() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+        let indices = Tensor::from_slice([1, 1], &[0]);
+
+        let _ = tensor.take_along_axis(1, &indices);
+    }
+
+    #[test]
+    fn test_gather() {
+        let tensor = Tensor::from_slice([3, 3], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let indices = Tensor::from_slice([2, 2], &[2, 1, 0, 2]);
+
+        let result = tensor.gather(0, &indices);
+
+        assert_eq!(result.shape(), &[2, 2]);
+        assert_eq!(result.as_slice(), &[7, 5, 1, 8]);
+    }
+
+    #[test]
+    fn test_scatter() {
+        let mut tensor = Tensor::new_set([3, 2], 0);
+        let indices = Tensor::from_slice([2, 2], &[2, 0, 0, 1]);
+        let source = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+
+        tensor.scatter(0, &indices, &source);
+
+        assert_eq!(tensor.as_slice(), &[3, 2, 0, 4, 1, 0]);
+    }
+
+    #[test]
+    fn test_try_scatter_out_of_bounds_unchanged() {
+        let mut tensor = Tensor::new_set([2, 2], 0);
+        let indices = Tensor::from_slice([2, 2], &[0, 1, 1, 2]);
+        let source = Tensor::new_set([2, 2], 1);
+
+        let result = tensor.try_scatter(1, &indices, &source);
+
+        assert_eq!(
+            result.unwrap_err(),
+            IndexError::OutOfBounds {
+                axis: 1,
+                index: 2,
+                len: 2
+            }
+        );
+        assert_eq!(tensor.as_slice(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 5 along axis 0 of length 3")]
+    fn test_scatter_add_out_of_bounds() {
+        let mut tensor = Tensor::new_set([3], 0);
+        let indices = Tensor::from_slice([2], &[1, 5]);
+        let source = Tensor::new_set([2], 1);
+
+        tensor.scatter_add(0, &indices, &source);
+    }
+
+    #[test]
+    fn test_scatter_add() {
+        let mut tensor = Tensor::new_set([2, 3], 0.0);
+        let indices = Tensor::from_slice([2, 2], &[0, 0, 2, 1]);
+        let source = Tensor::from_slice([2, 2], &[1.0, 2.0, 3.0, 4.0]);
+
+        tensor.scatter_add(1, &indices, &source);
+
+        assert_eq!(tensor.as_slice(), &[3.0, 0.0, 0.0, 0.0, 4.0, 3.0]);
+    }
+}
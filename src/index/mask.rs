@@ -0,0 +1,317 @@
+use core::hint::unreachable_unchecked;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+use crate::metadata::TensorMetadata;
+
+/// Counts the `true` values of `n` values of `m`.
+#[inline(always)]
+unsafe fn count_true(n: usize, m: *const bool) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            count += *m.add(i) as usize;
+        }
+        i += 1;
+    }
+    count
+}
+
+impl<T, const R: usize> Tensor<T, R> {
+    /// Selects element-wise from `if_true` where `mask` is `true`, and from `if_false` otherwise,
+    /// and returns the selection as new `Tensor<T, R>`.
+    ///
+    /// # Panics
+    /// This function will panic if the dimensions of `mask`, `if_true` and `if_false` do not
+    /// match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mask = Tensor::from_slice([2, 2], &[true, false, false, true]);
+    /// let a = Tensor::new_set([2, 2], 1);
+    /// let b = Tensor::new_set([2, 2], 2);
+    ///
+    /// let result = Tensor::select(&mask, &a, &b);
+    ///
+    /// assert_eq!(result.as_slice(), &[1, 2, 2, 1]);
+    /// ```
+    pub fn select(mask: &Tensor<bool, R>, if_true: &Self, if_false: &Self) -> Self
+    where
+        T: Copy,
+    {
+        assert_same_shape(mask, if_true);
+        assert_same_shape(mask, if_false);
+
+        // len is assumed to be > 0.
+        let len = mask.metadata.size();
+
+        let m = mask.data.as_ptr();
+        let a = if_true.data.as_ptr();
+        let b = if_false.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            let r = output.as_ptr_mut();
+
+            let mut i = 0;
+            while i < len {
+                let v = if *m.add(i) { *a.add(i) } else { *b.add(i) };
+                r.add(i).write(v);
+                i += 1;
+            }
+
+            Tensor {
+                metadata: if_true.metadata,
+                data: output,
+            }
+        }
+    }
+
+    /// Sets the values of `self` to `value` in-place where `mask` is `true`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `mask` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([4], &[1.0, f64::NAN, 3.0, f64::NAN]);
+    /// let mask = Tensor::from_slice([4], &[false, true, false, true]);
+    ///
+    /// tensor.masked_fill(&mask, 0.0);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1.0, 0.0, 3.0, 0.0]);
+    /// ```
+    pub fn masked_fill(&mut self, mask: &Tensor<bool, R>, value: T)
+    where
+        T: Copy,
+    {
+        assert_same_shape(self, mask);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let m = mask.data.as_ptr();
+
+        unsafe {
+            let mut i = 0;
+            while i < len {
+                if *m.add(i) {
+                    a.add(i).write(value);
+                }
+                i += 1;
+            }
+        }
+    }
+
+    /// Returns the values of `self` where `mask` is `true` as new `Tensor<T, 1>`, in row-major
+    /// order.
+    ///
+    /// Returns `None` if `mask` has no `true` values, since tensors can't be empty.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `mask` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+    /// let mask = Tensor::from_slice([2, 2], &[false, true, true, false]);
+    ///
+    /// let selected = tensor.masked_select(&mask).unwrap();
+    ///
+    /// assert_eq!(selected.shape(), &[2]);
+    /// assert_eq!(selected.as_slice(), &[2, 3]);
+    /// ```
+    pub fn masked_select(&self, mask: &Tensor<bool, R>) -> Option<Tensor<T, 1>>
+    where
+        T: Copy,
+    {
+        assert_same_shape(self, mask);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+        let m = mask.data.as_ptr();
+
+        unsafe {
+            let count = count_true(len, m);
+
+            if count == 0 {
+                return None;
+            }
+
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(count);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            let r = output.as_ptr_mut();
+
+            let mut j = 0;
+            let mut i = 0;
+            while i < len {
+                if *m.add(i) {
+                    r.add(j).write(*a.add(i));
+                    j += 1;
+                }
+                i += 1;
+            }
+
+            Some(Tensor {
+                metadata: TensorMetadata::new([count]),
+                data: output,
+            })
+        }
+    }
+
+    /// Copies the values of `source` in-place into `self` where `mask` is `true`.
+    ///
+    /// The values of `source` are consumed consecutively in row-major order of the positions
+    /// selected by `mask`, any remaining values of `source` are ignored.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - the dimensions of `self` and `mask` do not match.
+    /// - the size of `source` is less than the number of `true` values in `mask`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 2], 0);
+    /// let mask = Tensor::from_slice([2, 2], &[true, false, false, true]);
+    /// let source = Tensor::from_slice([3], &[7, 8, 9]);
+    ///
+    /// tensor.masked_scatter(&mask, &source);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[7, 0, 0, 8]);
+    /// ```
+    pub fn masked_scatter(&mut self, mask: &Tensor<bool, R>, source: &Tensor<T, 1>)
+    where
+        T: Copy,
+    {
+        assert_same_shape(self, mask);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let m = mask.data.as_ptr();
+        let s = source.data.as_ptr();
+
+        unsafe {
+            assert!(
+                count_true(len, m) <= source.metadata.size(),
+                "Source must have at least as many values as the `true` values of mask"
+            );
+
+            let mut j = 0;
+            let mut i = 0;
+            while i < len {
+                if *m.add(i) {
+                    a.add(i).write(*s.add(j));
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod mask_tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        let mask = Tensor::from_slice([2, 2], &[true, false, true, false]);
+        let a = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let b = Tensor::from_slice([2, 2], &[5, 6, 7, 8]);
+
+        let result = Tensor::select(&mask, &a, &b);
+
+        assert_eq!(result.shape(), &[2, 2]);
+        assert_eq!(result.as_slice(), &[1, 6, 3, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_invalid_shape() {
+        let mask = Tensor::new_set([2, 2], true);
+        let a = Tensor::new_set([2, 2], 1);
+        let b = Tensor::new_set([2, 3], 2);
+
+        let _ = Tensor::select(&mask, &a, &b);
+    }
+
+    #[test]
+    fn test_masked_fill() {
+        let mut tensor = Tensor::from_slice([2, 2], &[1.0, f64::NAN, 3.0, f64::NAN]);
+        let mask = Tensor::from_slice([2, 2], &[false, true, false, true]);
+
+        tensor.masked_fill(&mask, -1.0);
+
+        assert_eq!(tensor.as_slice(), &[1.0, -1.0, 3.0, -1.0]);
+    }
+
+    #[test]
+    fn test_masked_select() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+        let mask = Tensor::from_slice([2, 3], &[true, false, true, false, false, true]);
+
+        let selected = tensor.masked_select(&mask).unwrap();
+
+        assert_eq!(selected.shape(), &[3]);
+        assert_eq!(selected.as_slice(), &[1, 3, 6]);
+    }
+
+    #[test]
+    fn test_masked_select_none() {
+        let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let mask = Tensor::new_set([2, 2], false);
+
+        assert!(tensor.masked_select(&mask).is_none());
+    }
+
+    #[test]
+    fn test_masked_scatter() {
+        let mut tensor = Tensor::new_set([2, 3], 0);
+        let mask = Tensor::from_slice([2, 3], &[false, true, false, true, false, true]);
+        let source = Tensor::from_slice([3], &[1, 2, 3]);
+
+        tensor.masked_scatter(&mask, &source);
+
+        assert_eq!(tensor.as_slice(), &[0, 1, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_masked_scatter_short_source() {
+        let mut tensor = Tensor::new_set([2, 2], 0);
+        let mask = Tensor::new_set([2, 2], true);
+        let source = Tensor::from_slice([3], &[1, 2, 3]);
+
+        tensor.masked_scatter(&mask, &source);
+    }
+}
@@ -0,0 +1,6 @@
+mod error;
+mod gather;
+mod mask;
+
+// Public exports
+pub use error::IndexError;
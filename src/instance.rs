@@ -209,6 +209,33 @@ impl<T, const R: usize> Tensor<T, R> {
     }
 }
 
+impl<T, const R: usize> Tensor<T, R> {
+    /// Creates a new tensor with the provided metadata, and initializes the values in order with
+    /// the values returned by `f` for the offset of each value.
+    ///
+    /// This call is unwind-safe, initialized values are dropped if `f` panics.
+    ///
+    /// # Panics
+    /// This function will panic when:
+    /// - `T` is ZST.
+    /// - the size of the buffer overflows.
+    pub(crate) fn make_with<F>(metadata: TensorMetadata<R>, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        assert_not_zst::<T>();
+
+        let data = unsafe {
+            match UnmanagedPointer::make_with(metadata.size(), OnError::Panic, f) {
+                Ok(instance) => instance,
+                Err(_) => unreachable_unchecked(),
+            }
+        };
+
+        Self { metadata, data }
+    }
+}
+
 #[cfg(test)]
 mod instance_tests {
     use super::*;
@@ -0,0 +1,497 @@
+use crate::metadata::TensorMetadata;
+use crate::num::TotalOrder;
+use crate::{Metric, Tensor};
+
+use super::kmeans::kmeans;
+
+/// Number of rows of each side of the blocks of pairs computed at once.
+pub(super) const BLOCK: usize = 256;
+
+/// Maximum number of updates of the centroids of an approximate index.
+const KMEANS_ITERATIONS: usize = 20;
+
+/// Seed of the initialization of the centroids of an approximate index.
+const KMEANS_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// Returns the key of `score` with `metric`, where less is better: similarities are negated and
+/// distances are kept.
+#[inline(always)]
+pub(super) fn ranking_key(metric: Metric, score: f64) -> f64 {
+    match metric {
+        // NaN scores rank last whatever their sign.
+        _ if score.is_nan() => f64::NAN,
+        Metric::Dot | Metric::Cosine => -score,
+        _ => score,
+    }
+}
+
+/// Best `k` candidates of a query, as pairs of key and row.
+struct Candidates {
+    k: usize,
+    items: Vec<(f64, usize)>,
+}
+
+impl Candidates {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            items: Vec::with_capacity(2 * k),
+        }
+    }
+
+    /// Orders candidates by key, NaN last, and then by row.
+    fn cmp(a: &(f64, usize), b: &(f64, usize)) -> core::cmp::Ordering {
+        a.0.total_order(&b.0).then(a.1.cmp(&b.1))
+    }
+
+    /// Adds a candidate. The worst candidates are dropped once twice `k` are kept.
+    fn push(&mut self, key: f64, row: usize) {
+        self.items.push((key, row));
+
+        if self.items.len() == 2 * self.k {
+            self.items.select_nth_unstable_by(self.k - 1, Self::cmp);
+            self.items.truncate(self.k);
+        }
+    }
+
+    /// Returns the best `k` candidates, best first.
+    fn finish(mut self) -> Vec<(f64, usize)> {
+        self.items.sort_unstable_by(Self::cmp);
+        self.items.truncate(self.k);
+        self.items
+    }
+}
+
+/// Inverted file of an approximate index: the centroids of the lists, with the number of lists
+/// probed by a search.
+#[derive(Clone, Debug)]
+struct InvertedFile {
+    centroids: Tensor<f32, 2>,
+    probes: usize,
+}
+
+/// Index of vectors for the search of the nearest neighbors of queries with a `Metric`.
+///
+/// Nearest neighbors are the vectors with the greatest `Dot` or `Cosine` similarity, or the least
+/// distance with the other metrics.
+///
+/// An exact index compares each query with each vector, with matrix products for `Dot` and
+/// `Cosine`. An approximate index partitions the vectors into lists around
+/// centroids computed by k-means clustering (IVF), and compares each query only with the vectors
+/// of the lists with the nearest centroids. The number of probed lists trades speed for recall.
+///
+/// # Example
+///
+/// ```
+/// use tensor::{KnnIndex, Metric, Tensor};
+///
+/// let vectors = Tensor::from_slice([4, 2], &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0, -1.0, 0.0]);
+/// let index = KnnIndex::new(vectors, Metric::Cosine);
+///
+/// let queries = Tensor::from_slice([2, 2], &[2.0, 0.1, -1.0, -0.1]);
+/// let (scores, rows) = index.search(&queries, 2);
+///
+/// assert_eq!(rows.as_slice(), &[0, 2, 3, 1]);
+/// assert!(scores.as_slice()[2] > 0.99);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KnnIndex {
+    metric: Metric,
+    dim: usize,
+    /// Vectors of each list, with their rows in the original tensor. An exact index has a single
+    /// list.
+    lists: Vec<(Tensor<f32, 2>, Vec<usize>)>,
+    ivf: Option<InvertedFile>,
+}
+
+impl KnnIndex {
+    /// Creates an exact index of the rows of `vectors` with `metric`.
+    ///
+    /// # Panics
+    /// This function will panic if the order of `Metric::Minkowski` is less than `1` or NaN.
+    pub fn new(vectors: Tensor<f32, 2>, metric: Metric) -> Self {
+        if let Metric::Minkowski(p) = metric {
+            assert!(p >= 1.0, "Invalid norm order");
+        }
+
+        let [n, dim] = vectors.metadata.dims();
+
+        Self {
+            metric,
+            dim,
+            lists: vec![(vectors, (0..n).collect())],
+            ivf: None,
+        }
+    }
+
+    /// Creates an approximate index of the rows of `vectors` with `metric`, partitioned into at
+    /// most `lists` lists by k-means clustering. Lists left without vectors are dropped.
+    ///
+    /// Searches probe the nearest `ceil(sqrt(lists))` lists, see [`KnnIndex::set_probes`].
+    ///
+    /// # Panics
+    /// This function will panic if `lists` is `0` or greater than the number of vectors, or if
+    /// the order of `Metric::Minkowski` is less than `1` or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{KnnIndex, Metric, Tensor};
+    ///
+    /// let vectors = Tensor::from_slice([4, 1], &[0.0, 1.0, 10.0, 11.0]);
+    /// let mut index = KnnIndex::ivf(vectors, Metric::Euclidean, 2);
+    /// index.set_probes(1);
+    ///
+    /// let (distances, rows) = index.search(&Tensor::from_slice([1, 1], &[9.0]), 2);
+    ///
+    /// assert_eq!(rows.as_slice(), &[2, 3]);
+    /// assert_eq!(distances.as_slice(), &[1.0, 2.0]);
+    /// ```
+    pub fn ivf(vectors: Tensor<f32, 2>, metric: Metric, lists: usize) -> Self {
+        if let Metric::Minkowski(p) = metric {
+            assert!(p >= 1.0, "Invalid norm order");
+        }
+
+        let [n, dim] = vectors.metadata.dims();
+        assert!(lists > 0 && lists <= n, "Invalid number of lists");
+
+        let (centroids, assignments) =
+            kmeans(&vectors, metric, lists, KMEANS_ITERATIONS, KMEANS_SEED);
+
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); lists];
+        for (row, &c) in assignments.iter().enumerate() {
+            members[c].push(row);
+        }
+
+        let values = vectors.as_slice();
+        let centroid_values = centroids.as_slice();
+        let mut kept: Vec<f32> = Vec::with_capacity(lists * dim);
+        let mut index_lists = Vec::with_capacity(lists);
+
+        for (c, rows) in members.into_iter().enumerate() {
+            if rows.is_empty() {
+                continue;
+            }
+
+            let list = Tensor::make_with(TensorMetadata::new([rows.len(), dim]), |p| {
+                values[rows[p / dim] * dim + p % dim]
+            });
+
+            kept.extend_from_slice(&centroid_values[c * dim..(c + 1) * dim]);
+            index_lists.push((list, rows));
+        }
+
+        let centroids = Tensor::from_slice([index_lists.len(), dim], &kept);
+        let probes = (index_lists.len() as f64).sqrt().ceil() as usize;
+
+        Self {
+            metric,
+            dim,
+            lists: index_lists,
+            ivf: Some(InvertedFile { centroids, probes }),
+        }
+    }
+
+    /// Returns the number of indexed vectors.
+    pub fn len(&self) -> usize {
+        self.lists.iter().map(|(_, rows)| rows.len()).sum()
+    }
+
+    /// Returns `true` if the index has no vectors, which never happens as tensors are not empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the length of the indexed vectors.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the metric of the index.
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// Returns the number of lists probed by a search, or `None` for an exact index.
+    pub fn probes(&self) -> Option<usize> {
+        self.ivf.as_ref().map(|ivf| ivf.probes)
+    }
+
+    /// Sets the number of lists probed by a search of an approximate index, capped to the number
+    /// of lists. More probes give higher recall at the cost of speed, and probing all lists gives
+    /// the exact result. An exact index is not affected.
+    ///
+    /// # Panics
+    /// This method will panic if `probes` is `0`.
+    pub fn set_probes(&mut self, probes: usize) {
+        assert!(probes > 0, "Invalid number of probes");
+
+        let lists = self.lists.len();
+        if let Some(ivf) = &mut self.ivf {
+            ivf.probes = probes.min(lists);
+        }
+    }
+
+    /// Returns the `k` nearest neighbors of each row of `queries`, as the scores of the metric
+    /// and the rows of the indexed vectors, both with one row per query, nearest first.
+    ///
+    /// An approximate index compares the queries with the vectors of the probed lists, and
+    /// probes further lists for the queries with less than `k` candidates. Equal scores are
+    /// ordered by row and NaN scores are ordered last.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `queries` and the indexed vectors have different
+    /// lengths, or if `k` is `0` or greater than the number of indexed vectors.
+    pub fn search(&self, queries: &Tensor<f32, 2>, k: usize) -> (Tensor<f64, 2>, Tensor<usize, 2>) {
+        self.search_lists(queries, k, self.ivf.as_ref())
+    }
+
+    /// Returns the exact `k` nearest neighbors of each row of `queries`, comparing each query
+    /// with every indexed vector, also for an approximate index.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `queries` and the indexed vectors have different
+    /// lengths, or if `k` is `0` or greater than the number of indexed vectors.
+    pub fn search_exact(
+        &self,
+        queries: &Tensor<f32, 2>,
+        k: usize,
+    ) -> (Tensor<f64, 2>, Tensor<usize, 2>) {
+        self.search_lists(queries, k, None)
+    }
+
+    /// Searches the lists selected by `ivf` for each query, or every list if `None`.
+    fn search_lists(
+        &self,
+        queries: &Tensor<f32, 2>,
+        k: usize,
+        ivf: Option<&InvertedFile>,
+    ) -> (Tensor<f64, 2>, Tensor<usize, 2>) {
+        assert!(
+            k > 0 && k <= self.len(),
+            "Invalid number of elements to select"
+        );
+
+        let [q, dim] = queries.metadata.dims();
+        assert!(dim == self.dim, "Vectors must have the same length");
+
+        let metric = self.metric;
+        let mut candidates: Vec<Candidates> = (0..q).map(|_| Candidates::new(k)).collect();
+
+        // Queries of each list.
+        let probed: Vec<Vec<usize>> = match ivf {
+            Some(ivf) => self.probe(queries, k, ivf),
+            None => vec![(0..q).collect(); self.lists.len()],
+        };
+
+        let values = queries.as_slice();
+
+        for ((list, rows), members) in self.lists.iter().zip(&probed) {
+            if members.is_empty() {
+                continue;
+            }
+
+            let gathered;
+            let selected = if members.len() == q {
+                queries
+            } else {
+                gathered = Tensor::make_with(TensorMetadata::new([members.len(), dim]), |p| {
+                    values[members[p / dim] * dim + p % dim]
+                });
+                &gathered
+            };
+
+            selected.cdist_blocks(list, metric, BLOCK, |i0, j0, block| {
+                let [bm, bn] = block.metadata.dims();
+                let scores = block.as_slice();
+
+                let mut i = 0;
+                while i < bm {
+                    let query = &mut candidates[members[i0 + i]];
+                    let mut j = 0;
+                    while j < bn {
+                        query.push(ranking_key(metric, scores[i * bn + j]), rows[j0 + j]);
+                        j += 1;
+                    }
+                    i += 1;
+                }
+            });
+        }
+
+        let mut scores = Vec::with_capacity(q * k);
+        let mut neighbors = Vec::with_capacity(q * k);
+
+        for query in candidates {
+            for (key, row) in query.finish() {
+                scores.push(ranking_key(metric, key));
+                neighbors.push(row);
+            }
+        }
+
+        (
+            Tensor::from_boxed_slice([q, k], scores.into_boxed_slice()),
+            Tensor::from_boxed_slice([q, k], neighbors.into_boxed_slice()),
+        )
+    }
+
+    /// Returns the queries of each list, which probe the `ivf.probes` lists with the nearest
+    /// centroids and then further lists until they have at least `k` candidates.
+    fn probe(&self, queries: &Tensor<f32, 2>, k: usize, ivf: &InvertedFile) -> Vec<Vec<usize>> {
+        let lists = self.lists.len();
+        let distances = queries.cdist(&ivf.centroids, self.metric);
+
+        let mut probed: Vec<Vec<usize>> = vec![Vec::new(); lists];
+        let mut order: Vec<(f64, usize)> = Vec::with_capacity(lists);
+
+        for (query, row) in distances.as_slice().chunks_exact(lists).enumerate() {
+            order.clear();
+            order.extend(
+                row.iter()
+                    .enumerate()
+                    .map(|(c, &score)| (ranking_key(self.metric, score), c)),
+            );
+            order.sort_unstable_by(Candidates::cmp);
+
+            let mut count = 0;
+            for (p, &(_, c)) in order.iter().enumerate() {
+                if p >= ivf.probes && count >= k {
+                    break;
+                }
+                probed[c].push(query);
+                count += self.lists[c].1.len();
+            }
+        }
+
+        probed
+    }
+}
+
+#[cfg(test)]
+mod index_tests {
+    use super::*;
+
+    fn samples(m: usize, k: usize, seed: u64) -> Tensor<f32, 2> {
+        let mut rng = super::super::kmeans::Xorshift::new(seed);
+        let values: Vec<f32> = (0..m * k)
+            .map(|_| (rng.below(2000) as f32 / 100.0) - 10.0)
+            .collect();
+        Tensor::from_slice([m, k], &values)
+    }
+
+    /// Returns row `i` of `a` as tensor of rank 1.
+    fn row(a: &Tensor<f32, 2>, i: usize) -> Tensor<f32, 1> {
+        let [_, k] = a.metadata.dims();
+        Tensor::from_slice([k], &a.as_slice()[i * k..(i + 1) * k])
+    }
+
+    #[test]
+    fn test_exact_matches_brute_force() {
+        let vectors = samples(300, 8, 1);
+        let queries = samples(20, 8, 2);
+
+        for metric in [Metric::Cosine, Metric::Euclidean, Metric::Dot] {
+            let index = KnnIndex::new(vectors.clone(), metric);
+            let (scores, rows) = index.search(&queries, 5);
+
+            assert_eq!(rows.shape(), &[20, 5]);
+
+            for i in 0..20 {
+                let query = row(&queries, i);
+                let mut expected: Vec<(f64, usize)> = (0..300)
+                    .map(|j| {
+                        let vector = row(&vectors, j);
+                        let score = match metric {
+                            Metric::Cosine => query.cosine_similarity(&vector),
+                            Metric::Euclidean => query.euclidean_distance(&vector),
+                            _ => query.dot_product(&vector) as f64,
+                        };
+                        (ranking_key(metric, score), j)
+                    })
+                    .collect();
+                expected.sort_by(Candidates::cmp);
+
+                // The dot product of `f32` vectors is accumulated in `f32`.
+                for (p, &(key, j)) in expected.iter().take(5).enumerate() {
+                    let score = ranking_key(metric, key);
+                    assert_eq!(*rows.get(&[i, p]), j);
+                    assert!((scores.get(&[i, p]) - score).abs() < 1e-4 * score.abs().max(1.0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ivf_all_probes_is_exact() {
+        let vectors = samples(200, 4, 3);
+        let queries = samples(10, 4, 4);
+
+        let exact = KnnIndex::new(vectors.clone(), Metric::Euclidean);
+        let mut approximate = KnnIndex::ivf(vectors, Metric::Euclidean, 8);
+
+        assert_eq!(approximate.len(), 200);
+        assert_eq!(approximate.probes(), Some(3));
+
+        approximate.set_probes(usize::MAX);
+
+        assert_eq!(approximate.probes(), Some(8));
+        assert_eq!(approximate.search(&queries, 7), exact.search(&queries, 7));
+        assert_eq!(
+            approximate.search_exact(&queries, 7),
+            exact.search(&queries, 7)
+        );
+    }
+
+    #[test]
+    fn test_ivf_recall() {
+        // Clusters of vectors around 16 distinct centers, queried near the centers.
+        let centers = samples(16, 6, 5);
+        let noise = samples(800, 6, 6);
+        let vectors = Tensor::make_with(TensorMetadata::new([800, 6]), |p| {
+            centers.as_slice()[(p / 6) % 16 * 6 + p % 6] + noise.as_slice()[p] * 0.05
+        });
+        let queries = Tensor::make_with(TensorMetadata::new([16, 6]), |p| {
+            centers.as_slice()[p] + noise.as_slice()[p + 96] * 0.02
+        });
+
+        let mut index = KnnIndex::ivf(vectors.clone(), Metric::Euclidean, 16);
+        index.set_probes(2);
+
+        let (_, approximate) = index.search(&queries, 10);
+        let (_, exact) = index.search_exact(&queries, 10);
+
+        let found = approximate
+            .as_slice()
+            .chunks(10)
+            .zip(exact.as_slice().chunks(10))
+            .map(|(a, e)| a.iter().filter(|r| e.contains(r)).count())
+            .sum::<usize>();
+
+        assert!(found as f64 / 160.0 >= 0.9);
+    }
+
+    #[test]
+    fn test_ivf_probes_until_k_candidates() {
+        let vectors = Tensor::from_slice([5, 1], &[0.0, 1.0, 2.0, 100.0, 101.0]);
+        let mut index = KnnIndex::ivf(vectors, Metric::Manhattan, 2);
+        index.set_probes(1);
+
+        let (distances, rows) = index.search(&Tensor::from_slice([1, 1], &[0.0]), 4);
+
+        assert_eq!(rows.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(distances.as_slice(), &[0.0, 1.0, 2.0, 100.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of elements to select")]
+    fn test_search_too_many_neighbors() {
+        let index = KnnIndex::new(samples(3, 2, 7), Metric::Dot);
+
+        let _ = index.search(&samples(1, 2, 8), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of lists")]
+    fn test_ivf_too_many_lists() {
+        let _ = KnnIndex::ivf(samples(3, 2, 9), Metric::Cosine, 4);
+    }
+}
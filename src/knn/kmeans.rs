@@ -0,0 +1,179 @@
+use crate::metadata::TensorMetadata;
+use crate::num::{Accumulator, Summation};
+use crate::{Metric, Tensor};
+
+use super::index::{BLOCK, ranking_key};
+
+/// Xorshift pseudo-random generator, for reproducible initialization of the centroids.
+pub(super) struct Xorshift(u64);
+
+impl Xorshift {
+    /// Creates a generator from `seed`. The state must not be zero, so a zero seed is replaced.
+    pub(super) const fn new(seed: u64) -> Self {
+        Self(if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        })
+    }
+
+    /// Returns the next value of the sequence.
+    pub(super) const fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a value in the interval `[0, n)`.
+    pub(super) const fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Writes to `assignments` the position of the best centroid of each row of `vectors` with
+/// `metric`. Returns `true` if any assignment changed.
+fn assign(
+    vectors: &Tensor<f32, 2>,
+    centroids: &Tensor<f32, 2>,
+    metric: Metric,
+    assignments: &mut [usize],
+    keys: &mut [f64],
+) -> bool {
+    keys.fill(f64::INFINITY);
+    let mut best = vec![usize::MAX; assignments.len()];
+
+    vectors.cdist_blocks(centroids, metric, BLOCK, |i0, j0, block| {
+        let [bm, bn] = block.metadata.dims();
+        let values = block.as_slice();
+
+        let mut i = 0;
+        while i < bm {
+            let mut j = 0;
+            while j < bn {
+                let key = ranking_key(metric, values[i * bn + j]);
+                if key < keys[i0 + i] || best[i0 + i] == usize::MAX {
+                    keys[i0 + i] = key;
+                    best[i0 + i] = j0 + j;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+    });
+
+    let changed = best.as_slice() != assignments;
+    assignments.copy_from_slice(&best);
+    changed
+}
+
+/// Clusters the rows of `vectors` around `lists` centroids by k-means with `metric`, for at most
+/// `iterations` updates of the centroids. Returns the centroids and the position of the
+/// centroid of each row.
+///
+/// The centroids are initialized to distinct rows selected with `seed`, and are updated to the
+/// mean of their rows, summed with the global summation strategy. A centroid without rows keeps
+/// its position.
+pub(super) fn kmeans(
+    vectors: &Tensor<f32, 2>,
+    metric: Metric,
+    lists: usize,
+    iterations: usize,
+    seed: u64,
+) -> (Tensor<f32, 2>, Vec<usize>) {
+    let [n, k] = vectors.metadata.dims();
+    let values = vectors.as_slice();
+
+    // Partial Fisher-Yates shuffle of the rows, the first `lists` rows are selected.
+    let mut rng = Xorshift::new(seed);
+    let mut rows: Vec<usize> = (0..n).collect();
+    let mut l = 0;
+    while l < lists {
+        let r = l + rng.below(n - l);
+        rows.swap(l, r);
+        l += 1;
+    }
+
+    let mut centroids = Tensor::make_with(TensorMetadata::new([lists, k]), |p| {
+        values[rows[p / k] * k + p % k]
+    });
+
+    let mut assignments = vec![usize::MAX; n];
+    let mut keys = vec![0.0; n];
+    assign(vectors, &centroids, metric, &mut assignments, &mut keys);
+
+    let mut sum = Accumulator::new(Summation::global());
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); lists];
+
+    let mut iteration = 0;
+    while iteration < iterations {
+        members.iter_mut().for_each(Vec::clear);
+        for (row, &c) in assignments.iter().enumerate() {
+            members[c].push(row);
+        }
+
+        // The rows of each list are summed one coordinate at a time, reusing the accumulator.
+        for (centroid, rows) in centroids.as_slice_mut().chunks_exact_mut(k).zip(&members) {
+            if rows.is_empty() {
+                continue;
+            }
+            for (d, x) in centroid.iter_mut().enumerate() {
+                sum.reset();
+                rows.iter()
+                    .for_each(|&row| sum.add(values[row * k + d] as f64));
+                *x = (sum.sum() / rows.len() as f64) as f32;
+            }
+        }
+
+        if !assign(vectors, &centroids, metric, &mut assignments, &mut keys) {
+            break;
+        }
+        iteration += 1;
+    }
+
+    (centroids, assignments)
+}
+
+#[cfg(test)]
+mod kmeans_tests {
+    use super::*;
+
+    #[test]
+    fn test_xorshift() {
+        let mut a = Xorshift::new(7);
+        let mut b = Xorshift::new(7);
+
+        for _ in 0..100 {
+            let x = a.below(10);
+            assert_eq!(x, b.below(10));
+            assert!(x < 10);
+        }
+
+        assert_ne!(Xorshift::new(0).next_u64(), 0);
+    }
+
+    #[test]
+    fn test_kmeans_separated_clusters() {
+        // Two groups of points around (0, 0) and (10, 10).
+        let vectors = Tensor::from_slice(
+            [6, 2],
+            &[
+                0.0, 0.5, 10.0, 10.5, 0.5, 0.0, 10.5, 10.0, 0.0, 0.0, 10.0, 10.0,
+            ],
+        );
+
+        let (centroids, assignments) = kmeans(&vectors, Metric::Euclidean, 2, 10, 3);
+
+        assert_eq!(centroids.shape(), &[2, 2]);
+        assert_eq!(assignments[0], assignments[2]);
+        assert_eq!(assignments[0], assignments[4]);
+        assert_eq!(assignments[1], assignments[3]);
+        assert_eq!(assignments[1], assignments[5]);
+        assert_ne!(assignments[0], assignments[1]);
+
+        let c = assignments[1];
+        let centroid = &centroids.as_slice()[c * 2..(c + 1) * 2];
+        assert!((centroid[0] - 10.1667).abs() < 1e-3);
+        assert!((centroid[1] - 10.1667).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,5 @@
+mod index;
+mod kmeans;
+
+// Public exports
+pub use index::KnnIndex;
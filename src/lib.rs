@@ -1,16 +1,29 @@
 mod access;
 mod assertions;
 mod cast;
+mod index;
 mod instance;
+mod knn;
+mod linalg;
 mod mem;
 mod metadata;
+mod num;
 mod ops;
+mod stats;
 mod tensor;
 mod transform;
 
 // Public exports
 pub use crate::cast::{CastError, TryCast};
+pub use crate::index::IndexError;
+pub use crate::knn::KnnIndex;
+pub use crate::linalg::{LinalgError, Lu};
 pub use crate::mem::error::MemoryError;
+pub use crate::num::{Float, Integer, Summation, TotalOrder};
+pub use crate::ops::{
+    ArithmeticError, ConvOptions, Diagonal, Metric, PaddingMode, Window, Windows,
+};
+pub use crate::stats::Interpolation;
 pub use crate::tensor::Tensor;
 
 pub use meta::tensor;
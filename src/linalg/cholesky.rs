@@ -0,0 +1,107 @@
+use crate::Tensor;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::lu::square;
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Computes the Cholesky factorization `A = L L^T` of the symmetric positive definite matrix
+    /// and returns the lower triangular factor `L`.
+    ///
+    /// Only the lower triangle of the matrix is read.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    /// - `NotPositiveDefinite`: The matrix is not positive definite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{LinalgError, Tensor};
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[4.0, 2.0, 2.0, 10.0]);
+    /// let indefinite = Tensor::from_slice([2, 2], &[1.0, 2.0, 2.0, 1.0]);
+    ///
+    /// let l = matrix.cholesky().unwrap();
+    ///
+    /// assert_eq!(l.as_slice(), &[2.0, 0.0, 1.0, 3.0]);
+    /// assert_eq!(indefinite.cholesky(), Err(LinalgError::NotPositiveDefinite { pivot: 1 }));
+    /// ```
+    pub fn cholesky(&self) -> Result<Self, LinalgError> {
+        let [rows, cols] = self.metadata.dims();
+        let n = square(rows, cols)?;
+
+        let a = self.as_slice();
+        let mut output = Tensor::new_set([n, n], T::ZERO);
+        let l = output.as_slice_mut();
+
+        let mut j = 0;
+        while j < n {
+            let mut diagonal = a[j * n + j];
+            let mut k = 0;
+            while k < j {
+                diagonal -= l[j * n + k] * l[j * n + k];
+                k += 1;
+            }
+
+            if diagonal <= T::ZERO || diagonal.is_nan() {
+                return Err(LinalgError::NotPositiveDefinite { pivot: j });
+            }
+
+            let diagonal = diagonal.sqrt();
+            l[j * n + j] = diagonal;
+
+            let mut i = j + 1;
+            while i < n {
+                let mut value = a[i * n + j];
+                let mut k = 0;
+                while k < j {
+                    value -= l[i * n + k] * l[j * n + k];
+                    k += 1;
+                }
+                l[i * n + j] = value / diagonal;
+                i += 1;
+            }
+            j += 1;
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod cholesky_tests {
+    use super::*;
+
+    #[test]
+    fn test_cholesky_reconstruction() {
+        let a = Tensor::from_slice(
+            [3, 3],
+            &[25.0, 15.0, -5.0, 15.0, 18.0, 0.0, -5.0, 0.0, 11.0],
+        );
+
+        let l = a.cholesky().unwrap();
+
+        assert_eq!(
+            l.as_slice(),
+            &[5.0, 0.0, 0.0, 3.0, 3.0, 0.0, -1.0, 1.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_cholesky_errors() {
+        let singular = Tensor::from_slice([2, 2], &[1.0f32, 1.0, 1.0, 1.0]);
+
+        assert_eq!(
+            singular.cholesky(),
+            Err(LinalgError::NotPositiveDefinite { pivot: 1 })
+        );
+        assert_eq!(
+            Tensor::new_set([1, 2], 1.0).cholesky(),
+            Err(LinalgError::NotSquare { rows: 1, cols: 2 })
+        );
+    }
+}
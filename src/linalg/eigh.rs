@@ -0,0 +1,201 @@
+use crate::Tensor;
+use crate::metadata::TensorMetadata;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::lu::square;
+
+/// Maximum number of sweeps of the Jacobi methods over all pairs of rows and columns.
+pub(super) const MAX_SWEEPS: usize = 100;
+
+/// Returns the tangent of the Jacobi rotation angle that zeroes the off-diagonal value of the
+/// symmetric `2 x 2` matrix whose diagonal values differ by `2 * zeta` times the off-diagonal
+/// value, choosing the smaller angle.
+#[inline(always)]
+pub(super) fn rotation_tangent<T>(zeta: T) -> T
+where
+    T: Float,
+{
+    let sign = if zeta >= T::ZERO { T::ONE } else { -T::ONE };
+    sign / (zeta.abs() + zeta.hypot(T::ONE))
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Computes the eigen decomposition `A = V diag(w) V^T` of the symmetric matrix with the
+    /// cyclic Jacobi method, and returns the eigenvalues `w` in ascending order and the matrix
+    /// `V` whose columns are the corresponding orthonormal eigenvectors.
+    ///
+    /// Only the lower triangle of the matrix is read.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    /// - `NoConvergence`: The off-diagonal values were not reduced to working precision, which
+    ///   may happen for matrices with NaN or infinite values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[2.0f64, 1.0, 1.0, 2.0]);
+    ///
+    /// let (w, v) = matrix.eigh().unwrap();
+    ///
+    /// assert!((w.as_slice()[0] - 1.0).abs() < 1e-12);
+    /// assert!((w.as_slice()[1] - 3.0).abs() < 1e-12);
+    /// assert!((v.get(&[0, 1]).abs() - 0.5f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn eigh(&self) -> Result<(Tensor<T, 1>, Self), LinalgError> {
+        let [rows, cols] = self.metadata.dims();
+        let n = square(rows, cols)?;
+
+        let values = self.as_slice();
+        let mut a: Vec<T> = (0..n * n)
+            .map(|p| {
+                let (i, j) = (p / n, p % n);
+                values[i.max(j) * n + i.min(j)]
+            })
+            .collect();
+        let mut v: Vec<T> = (0..n * n)
+            .map(|p| if p / n == p % n { T::ONE } else { T::ZERO })
+            .collect();
+
+        let mut total = T::ZERO;
+        for &x in &a {
+            total = total.hypot(x);
+        }
+        // The stop test below always holds for an infinite norm.
+        if total == T::INFINITY || total.is_nan() {
+            return Err(LinalgError::NoConvergence { sweeps: 0 });
+        }
+
+        let mut sweep = 0;
+        loop {
+            let mut off = T::ZERO;
+            for p in 0..n {
+                for q in 0..n {
+                    if p != q {
+                        off = off.hypot(a[p * n + q]);
+                    }
+                }
+            }
+
+            if off <= T::EPSILON * total {
+                break;
+            }
+            if sweep == MAX_SWEEPS || off.is_nan() {
+                return Err(LinalgError::NoConvergence { sweeps: sweep });
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a[p * n + q];
+                    if apq == T::ZERO {
+                        continue;
+                    }
+
+                    let zeta = (a[q * n + q] - a[p * n + p]) / (apq + apq);
+                    let t = rotation_tangent(zeta);
+                    let c = (t * t + T::ONE).sqrt().recip();
+                    let s = t * c;
+
+                    a[p * n + p] -= t * apq;
+                    a[q * n + q] += t * apq;
+                    a[p * n + q] = T::ZERO;
+                    a[q * n + p] = T::ZERO;
+
+                    for r in 0..n {
+                        if r != p && r != q {
+                            let (arp, arq) = (a[r * n + p], a[r * n + q]);
+                            let (rp, rq) = (c * arp - s * arq, s * arp + c * arq);
+                            a[r * n + p] = rp;
+                            a[p * n + r] = rp;
+                            a[r * n + q] = rq;
+                            a[q * n + r] = rq;
+                        }
+
+                        let (vrp, vrq) = (v[r * n + p], v[r * n + q]);
+                        v[r * n + p] = c * vrp - s * vrq;
+                        v[r * n + q] = s * vrp + c * vrq;
+                    }
+                }
+            }
+            sweep += 1;
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            a[i * n + i]
+                .partial_cmp(&a[j * n + j])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let w = Tensor::make_with(TensorMetadata::new([n]), |i| a[order[i] * n + order[i]]);
+        let vectors =
+            Tensor::make_with(TensorMetadata::new([n, n]), |p| v[p / n * n + order[p % n]]);
+
+        Ok((w, vectors))
+    }
+}
+
+#[cfg(test)]
+mod eigh_tests {
+    use super::*;
+
+    #[test]
+    fn test_eigh_reconstruction() {
+        let a = Tensor::from_slice(
+            [4, 4],
+            &[
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+        );
+
+        let (w, v) = a.eigh().unwrap();
+        let (w, v) = (w.as_slice(), v.as_slice());
+
+        // A V = V diag(w) and V^T V = I.
+        for i in 0..4 {
+            for j in 0..4 {
+                let av: f64 = (0..4).map(|k| a.as_slice()[i * 4 + k] * v[k * 4 + j]).sum();
+                assert!((av - v[i * 4 + j] * w[j]).abs() < 1e-10);
+
+                let vtv: f64 = (0..4).map(|k| v[k * 4 + i] * v[k * 4 + j]).sum();
+                assert!((vtv - (i == j) as u8 as f64).abs() < 1e-10);
+            }
+        }
+
+        assert!(w.windows(2).all(|w| w[0] <= w[1]));
+        assert!((w.iter().sum::<f64>() - 8.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_eigh_f32_and_lower_triangle() {
+        // The upper triangle is ignored.
+        let a = Tensor::from_slice([3, 3], &[2.0f32, 9.0, 9.0, 0.0, 3.0, 9.0, 0.0, 0.0, 1.0]);
+
+        let (w, v) = a.eigh().unwrap();
+
+        assert_eq!(w.as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(v.as_slice(), &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_eigh_errors() {
+        let nan = Tensor::from_slice([2, 2], &[1.0, f64::NAN, f64::NAN, 1.0]);
+        let infinite = Tensor::from_slice([2, 2], &[1.0, f64::INFINITY, f64::INFINITY, 1.0]);
+
+        assert_eq!(
+            Tensor::new_set([2, 3], 1.0).eigh().map(|_| ()),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+        assert!(matches!(nan.eigh(), Err(LinalgError::NoConvergence { .. })));
+        assert_eq!(
+            infinite.eigh().map(|_| ()),
+            Err(LinalgError::NoConvergence { sweeps: 0 })
+        );
+    }
+}
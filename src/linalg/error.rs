@@ -0,0 +1,20 @@
+/// Error type for linear algebra operations.
+/// This enum is used to represent the different types of errors that can occur when factorizing
+/// matrices or solving linear systems.
+/// The following errors are defined:
+/// - `NotSquare`: The matrix has `rows` rows and `cols` columns, but must be square.
+/// - `DimensionMismatch`: The right-hand side has `found` rows (or matrices), but `expected` are
+///   required.
+/// - `Singular`: The matrix at position `batch` of a batch, `0` for a single matrix, is singular
+///   to working precision, as shown by the pivot of column `pivot`.
+/// - `NotPositiveDefinite`: The matrix is not positive definite, as shown by the pivot of column
+///   `pivot`.
+/// - `NoConvergence`: The iterative algorithm did not converge after `sweeps` sweeps.
+#[derive(Debug, PartialEq)]
+pub enum LinalgError {
+    NotSquare { rows: usize, cols: usize },
+    DimensionMismatch { expected: usize, found: usize },
+    Singular { batch: usize, pivot: usize },
+    NotPositiveDefinite { pivot: usize },
+    NoConvergence { sweeps: usize },
+}
@@ -0,0 +1,409 @@
+use crate::Tensor;
+use crate::assertions::assert_right_hand_rank;
+use crate::metadata::TensorMetadata;
+use crate::num::Float;
+
+use super::LinalgError;
+
+/// Returns the dimension of a square matrix with `rows` rows and `cols` columns.
+#[inline(always)]
+pub(super) const fn square(rows: usize, cols: usize) -> Result<usize, LinalgError> {
+    if rows == cols {
+        Ok(rows)
+    } else {
+        Err(LinalgError::NotSquare { rows, cols })
+    }
+}
+
+/// Factorizes in-place the row-major `n x n` matrix `a` as `P A = L U` with partial pivoting,
+/// where the strictly lower part of `a` becomes `L` without its unit diagonal and the upper part
+/// becomes `U`. Row `i` of `P A` is row `permutation[i]` of `A`.
+///
+/// Returns the sign of the permutation and the first column whose pivot is not greater than
+/// `n * epsilon * max |a|` in absolute value, if any.
+fn factorize<T>(a: &mut [T], n: usize, permutation: &mut [usize]) -> (T, Option<usize>)
+where
+    T: Float,
+{
+    let mut max = T::ZERO;
+    for x in a.iter() {
+        if x.abs() > max {
+            max = x.abs();
+        }
+    }
+    let tolerance = T::from_f64(n as f64) * T::EPSILON * max;
+
+    for (i, p) in permutation.iter_mut().enumerate() {
+        *p = i;
+    }
+
+    let mut sign = T::ONE;
+    let mut singular = None;
+
+    let mut j = 0;
+    while j < n {
+        // Greatest value of the column, on or below the diagonal.
+        let mut p = j;
+        let mut i = j + 1;
+        while i < n {
+            if a[i * n + j].abs() > a[p * n + j].abs() {
+                p = i;
+            }
+            i += 1;
+        }
+
+        if p != j {
+            let (upper, lower) = a.split_at_mut(p * n);
+            upper[j * n..(j + 1) * n].swap_with_slice(&mut lower[..n]);
+            permutation.swap(j, p);
+            sign = -sign;
+        }
+
+        let pivot = a[j * n + j];
+
+        if pivot.abs() <= tolerance || pivot.is_nan() {
+            singular.get_or_insert(j);
+
+            // The column is zero below the diagonal, nothing is eliminated.
+            if pivot == T::ZERO {
+                j += 1;
+                continue;
+            }
+        }
+
+        let mut i = j + 1;
+        while i < n {
+            let factor = a[i * n + j] / pivot;
+            a[i * n + j] = factor;
+
+            let mut c = j + 1;
+            while c < n {
+                let u = a[j * n + c];
+                a[i * n + c] -= factor * u;
+                c += 1;
+            }
+            i += 1;
+        }
+        j += 1;
+    }
+
+    (sign, singular)
+}
+
+/// Solves in-place `A X = B` for the row-major `n x m` matrix `b`, with the factors of `A` and
+/// the permutation computed by `factorize`.
+fn substitute<T>(factors: &[T], n: usize, permutation: &[usize], b: &mut [T], m: usize)
+where
+    T: Float,
+{
+    let permuted: Vec<T> = permutation
+        .iter()
+        .flat_map(|&p| b[p * m..(p + 1) * m].iter().copied())
+        .collect();
+    b.copy_from_slice(&permuted);
+
+    // Forward substitution with the unit lower triangular `L`.
+    let mut i = 1;
+    while i < n {
+        let mut k = 0;
+        while k < i {
+            let l = factors[i * n + k];
+            if l != T::ZERO {
+                let mut c = 0;
+                while c < m {
+                    let y = b[k * m + c];
+                    b[i * m + c] -= l * y;
+                    c += 1;
+                }
+            }
+            k += 1;
+        }
+        i += 1;
+    }
+
+    // Backward substitution with the upper triangular `U`.
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+
+        let mut k = i + 1;
+        while k < n {
+            let u = factors[i * n + k];
+            let mut c = 0;
+            while c < m {
+                let x = b[k * m + c];
+                b[i * m + c] -= u * x;
+                c += 1;
+            }
+            k += 1;
+        }
+
+        let d = factors[i * n + i];
+        let mut c = 0;
+        while c < m {
+            b[i * m + c] /= d;
+            c += 1;
+        }
+    }
+}
+
+/// LU factorization `P A = L U` of a square matrix `A` with partial pivoting, where `P` is a
+/// permutation matrix, `L` is unit lower triangular and `U` is upper triangular.
+///
+/// The factorization exists for any square matrix, but solving systems requires `A` not to be
+/// singular. A matrix is considered singular when a pivot is not greater than
+/// `n * epsilon * max |A|` in absolute value.
+#[derive(Clone, Debug)]
+pub struct Lu<T> {
+    /// `L` without its unit diagonal below the diagonal, and `U` on and above it.
+    factors: Tensor<T, 2>,
+    permutation: Vec<usize>,
+    sign: T,
+    singular: Option<usize>,
+}
+
+impl<T> Lu<T>
+where
+    T: Float,
+{
+    /// Factorizes the row-major `n x n` matrix `values`.
+    pub(super) fn from_values(values: &[T], n: usize) -> Self {
+        let mut factors = Tensor::from_slice([n, n], values);
+        let mut permutation = vec![0; n];
+        let (sign, singular) = factorize(factors.as_slice_mut(), n, &mut permutation);
+
+        Self {
+            factors,
+            permutation,
+            sign,
+            singular,
+        }
+    }
+
+    /// Solves in-place `A X = B` for the row-major `n x m` matrix `b`, with `batch` as position
+    /// of `A` reported by errors.
+    pub(super) fn solve_values(
+        &self,
+        b: &mut [T],
+        m: usize,
+        batch: usize,
+    ) -> Result<(), LinalgError> {
+        if let Some(pivot) = self.singular {
+            return Err(LinalgError::Singular { batch, pivot });
+        }
+
+        let n = self.permutation.len();
+        substitute(self.factors.as_slice(), n, &self.permutation, b, m);
+        Ok(())
+    }
+
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> Tensor<T, 2> {
+        let n = self.permutation.len();
+        let factors = self.factors.as_slice();
+
+        Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+            let (i, j) = (p / n, p % n);
+            match i.cmp(&j) {
+                core::cmp::Ordering::Greater => factors[p],
+                core::cmp::Ordering::Equal => T::ONE,
+                core::cmp::Ordering::Less => T::ZERO,
+            }
+        })
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> Tensor<T, 2> {
+        let n = self.permutation.len();
+        let factors = self.factors.as_slice();
+
+        Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+            if p / n <= p % n { factors[p] } else { T::ZERO }
+        })
+    }
+
+    /// Returns the permutation matrix `P`.
+    pub fn p(&self) -> Tensor<T, 2> {
+        let n = self.permutation.len();
+
+        Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+            if self.permutation[p / n] == p % n {
+                T::ONE
+            } else {
+                T::ZERO
+            }
+        })
+    }
+
+    /// Returns the permutation of the rows, where row `i` of `P A` is row `permutation[i]` of
+    /// `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns `true` if the matrix is singular to working precision.
+    pub fn is_singular(&self) -> bool {
+        self.singular.is_some()
+    }
+
+    /// Returns the determinant of the matrix, the product of the diagonal of `U` with the sign
+    /// of the permutation.
+    pub fn det(&self) -> T {
+        let n = self.permutation.len();
+        let factors = self.factors.as_slice();
+
+        let mut det = self.sign;
+        let mut i = 0;
+        while i < n {
+            det *= factors[i * n + i];
+            i += 1;
+        }
+        det
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant,
+    /// which does not overflow or underflow for large matrices.
+    ///
+    /// The sign is `0` and the logarithm is negative infinity if the determinant is `0`.
+    pub fn slogdet(&self) -> (T, T) {
+        let n = self.permutation.len();
+        let factors = self.factors.as_slice();
+
+        let mut sign = self.sign;
+        let mut log = T::ZERO;
+        let mut i = 0;
+        while i < n {
+            let d = factors[i * n + i];
+            if d == T::ZERO {
+                return (T::ZERO, T::NEG_INFINITY);
+            }
+            sign *= d.signum();
+            log += d.abs().ln();
+            i += 1;
+        }
+        (sign, log)
+    }
+
+    /// Solves `A x = b` for a vector `b` as `Tensor<T, 1>`, or `A X = B` for a matrix `B` as
+    /// `Tensor<T, 2>`, and returns the solution with the shape of `b`.
+    ///
+    /// # Errors
+    /// - `DimensionMismatch`: The length of `b` along the first axis is not the dimension of `A`.
+    /// - `Singular`: `A` is singular.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is not `1` or `2`.
+    pub fn solve<const N: usize>(&self, b: &Tensor<T, N>) -> Result<Tensor<T, N>, LinalgError> {
+        assert_right_hand_rank::<2, N>();
+
+        let n = self.permutation.len();
+        let rows = b.metadata.dims()[0];
+        if rows != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: n,
+                found: rows,
+            });
+        }
+
+        let mut x = b.clone();
+        let m = b.metadata.size() / n;
+        self.solve_values(x.as_slice_mut(), m, 0)?;
+        Ok(x)
+    }
+
+    /// Returns the inverse of the matrix.
+    ///
+    /// # Errors
+    /// - `Singular`: The matrix is singular.
+    pub fn inv(&self) -> Result<Tensor<T, 2>, LinalgError> {
+        let n = self.permutation.len();
+        let mut x = Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+            if p / n == p % n { T::ONE } else { T::ZERO }
+        });
+
+        self.solve_values(x.as_slice_mut(), n, 0)?;
+        Ok(x)
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Computes the LU factorization `P A = L U` of the matrix with partial pivoting.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[1.0, 2.0, 4.0, 4.0]);
+    ///
+    /// let lu = matrix.lu().unwrap();
+    ///
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// assert_eq!(lu.l().as_slice(), &[1.0, 0.0, 0.25, 1.0]);
+    /// assert_eq!(lu.u().as_slice(), &[4.0, 4.0, 0.0, 1.0]);
+    /// assert_eq!(lu.det(), -4.0);
+    /// ```
+    pub fn lu(&self) -> Result<Lu<T>, LinalgError> {
+        let [rows, cols] = self.metadata.dims();
+        let n = square(rows, cols)?;
+
+        Ok(Lu::from_values(self.as_slice(), n))
+    }
+}
+
+#[cfg(test)]
+mod lu_tests {
+    use super::*;
+
+    use crate::linalg::testing::matmul;
+
+    #[test]
+    fn test_lu_reconstruction() {
+        let a = Tensor::from_slice(
+            [4, 4],
+            &[
+                2.0, -1.0, 0.0, 3.0, 4.0, 1.0, -2.0, 0.5, -6.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0,
+            ],
+        );
+
+        let lu = a.lu().unwrap();
+
+        let pa = matmul(&lu.p(), &a, false);
+        let product = matmul(&lu.l(), &lu.u(), false);
+
+        for (x, y) in pa.iter().zip(product.iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        assert!(!lu.is_singular());
+        assert_eq!(lu.permutation()[0], 2);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let a = Tensor::from_slice([3, 3], &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
+
+        let lu = a.lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.det(), 0.0);
+        assert_eq!(lu.slogdet(), (0.0, f64::NEG_INFINITY));
+        assert_eq!(lu.inv(), Err(LinalgError::Singular { batch: 0, pivot: 2 }));
+    }
+
+    #[test]
+    fn test_lu_not_square() {
+        let a = Tensor::new_set([2, 3], 1.0f32);
+
+        assert_eq!(
+            a.lu().map(|_| ()),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+}
@@ -0,0 +1,40 @@
+mod cholesky;
+mod eigh;
+mod error;
+mod lu;
+mod qr;
+mod solve;
+mod svd;
+mod triangular;
+
+// Public exports
+pub use error::LinalgError;
+pub use lu::Lu;
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use crate::Tensor;
+
+    /// Returns the matrix product of `a`, or of its transpose if `transpose_a`, with `b`.
+    pub(crate) fn matmul(
+        a: &Tensor<f64, 2>,
+        b: &Tensor<f64, 2>,
+        transpose_a: bool,
+    ) -> Tensor<f64, 2> {
+        let axis = if transpose_a { 0 } else { 1 };
+        a.tensordot(b, [(axis, 0)])
+    }
+
+    /// Asserts that `a` and `b` have the same length and equal values up to rounding.
+    pub(crate) fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-10, "{x} != {y}");
+        }
+    }
+
+    /// Returns the values of the identity matrix of order `n`.
+    pub(crate) fn identity(n: usize) -> Vec<f64> {
+        (0..n * n).map(|p| (p / n == p % n) as u8 as f64).collect()
+    }
+}
@@ -0,0 +1,368 @@
+use crate::Tensor;
+use crate::assertions::assert_right_hand_rank;
+use crate::metadata::TensorMetadata;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::triangular::{right_hand_cols, substitute_triangular};
+
+/// Applies the reflection `I - 2 v v^T` to the rows of the row-major matrix `b` with `cols`
+/// columns, where row `r` of `b` corresponds to `v[r]`.
+fn reflect<T>(v: &[T], b: &mut [T], cols: usize)
+where
+    T: Float,
+{
+    let two = T::ONE + T::ONE;
+
+    let mut c = 0;
+    while c < cols {
+        let mut dot = T::ZERO;
+        for (r, &v_r) in v.iter().enumerate() {
+            dot += v_r * b[r * cols + c];
+        }
+
+        if dot != T::ZERO {
+            let scale = two * dot;
+            for (r, &v_r) in v.iter().enumerate() {
+                b[r * cols + c] -= scale * v_r;
+            }
+        }
+        c += 1;
+    }
+}
+
+/// Returns the Euclidean norm of `values`, without intermediate overflow.
+fn norm<T>(values: &[T]) -> T
+where
+    T: Float,
+{
+    values.iter().fold(T::ZERO, |norm, &x| norm.hypot(x))
+}
+
+/// Householder QR factorization of a row-major `m x n` matrix `A = Q R`, where `Q` is the
+/// product of the reflections `H_0 ... H_(k - 1)` with `k = min(m, n)`.
+struct Householder<T> {
+    m: usize,
+    n: usize,
+    /// `R` on and above the diagonal of the first `k` rows.
+    factors: Vec<T>,
+    /// Unit vector `v_j` of the reflection `H_j = I - 2 v_j v_j^T` of rows `j..m`, zero if `H_j`
+    /// is the identity.
+    reflectors: Vec<Vec<T>>,
+}
+
+impl<T> Householder<T>
+where
+    T: Float,
+{
+    fn new(a: &[T], m: usize, n: usize) -> Self {
+        let mut factors = a.to_vec();
+        let k = m.min(n);
+        let mut reflectors = Vec::with_capacity(k);
+
+        let mut j = 0;
+        while j < k {
+            let mut v: Vec<T> = (j..m).map(|i| factors[i * n + j]).collect();
+            let length = norm(&v);
+
+            if length != T::ZERO {
+                // The sign is chosen to avoid cancellation.
+                let first = v[0];
+                v[0] += if first > T::ZERO { length } else { -length };
+
+                let length = norm(&v);
+                v.iter_mut().for_each(|x| *x /= length);
+
+                reflect(&v, &mut factors[j * n..], n);
+            } else {
+                v.fill(T::ZERO);
+            }
+
+            reflectors.push(v);
+            j += 1;
+        }
+
+        Self {
+            m,
+            n,
+            factors,
+            reflectors,
+        }
+    }
+
+    /// Replaces the row-major `m x cols` matrix `b` with `Q^T b`.
+    fn apply_qt(&self, b: &mut [T], cols: usize) {
+        for (j, v) in self.reflectors.iter().enumerate() {
+            reflect(v, &mut b[j * cols..], cols);
+        }
+    }
+
+    /// Replaces the row-major `m x cols` matrix `b` with `Q b`.
+    fn apply_q(&self, b: &mut [T], cols: usize) {
+        for (j, v) in self.reflectors.iter().enumerate().rev() {
+            reflect(v, &mut b[j * cols..], cols);
+        }
+    }
+
+    /// Returns the first `rows` columns of `Q`.
+    fn q(&self, rows: usize) -> Tensor<T, 2> {
+        let m = self.m;
+        let mut q = Tensor::make_with(TensorMetadata::new([m, rows]), |p| {
+            if p / rows == p % rows {
+                T::ONE
+            } else {
+                T::ZERO
+            }
+        });
+
+        self.apply_q(q.as_slice_mut(), rows);
+        q
+    }
+
+    /// Returns the first `rows` rows of `R`.
+    fn r(&self, rows: usize) -> Tensor<T, 2> {
+        let n = self.n;
+
+        Tensor::make_with(TensorMetadata::new([rows, n]), |p| {
+            if p / n <= p % n {
+                self.factors[p]
+            } else {
+                T::ZERO
+            }
+        })
+    }
+
+    /// Returns the first column whose value on the diagonal of `R` is not greater than
+    /// `max(m, n) * epsilon * max |R_ii|` in absolute value, if any.
+    fn rank_deficiency(&self) -> Option<usize> {
+        let (m, n) = (self.m, self.n);
+        let diagonal = |i: usize| self.factors[i * n + i].abs();
+
+        let k = m.min(n);
+        let mut max = T::ZERO;
+        for i in 0..k {
+            if diagonal(i) > max {
+                max = diagonal(i);
+            }
+        }
+
+        let tolerance = T::from_f64(m.max(n) as f64) * T::EPSILON * max;
+        (0..k).find(|&i| diagonal(i) <= tolerance || diagonal(i).is_nan())
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Computes the reduced QR factorization `A = Q R` of the `m x n` matrix with Householder
+    /// reflections, where `Q` is `m x k` with orthonormal columns, `R` is `k x n` upper
+    /// triangular, and `k` is `min(m, n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([3, 2], &[3.0f64, 1.0, 4.0, 2.0, 0.0, 5.0]);
+    ///
+    /// let (q, r) = matrix.qr();
+    ///
+    /// assert_eq!(q.shape(), &[3, 2]);
+    /// assert_eq!(r.shape(), &[2, 2]);
+    /// assert_eq!(*r.get(&[1, 0]), 0.0);
+    /// assert!((r.get(&[0, 0]).abs() - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn qr(&self) -> (Self, Self) {
+        let [m, n] = self.metadata.dims();
+        let householder = Householder::new(self.as_slice(), m, n);
+        let k = m.min(n);
+
+        (householder.q(k), householder.r(k))
+    }
+
+    /// Computes the complete QR factorization `A = Q R` of the `m x n` matrix with Householder
+    /// reflections, where `Q` is `m x m` orthogonal and `R` is `m x n` upper triangular.
+    pub fn qr_complete(&self) -> (Self, Self) {
+        let [m, n] = self.metadata.dims();
+        let householder = Householder::new(self.as_slice(), m, n);
+
+        (householder.q(m), householder.r(m))
+    }
+
+    /// Solves the linear least-squares problem `min |A x - b|` for the `m x n` matrix `A` and a
+    /// vector `b` as `Tensor<T, 1>`, or each column of a matrix `B` as `Tensor<T, 2>`, with the
+    /// QR factorization of `A`. The solution has `n` rows and the columns of `b`.
+    ///
+    /// If `m` is less than `n`, the system is underdetermined and the solution of least norm is
+    /// returned, computed with the QR factorization of `A^T`.
+    ///
+    /// # Errors
+    /// - `DimensionMismatch`: The length of `b` along the first axis is not `m`.
+    /// - `Singular`: The matrix is rank deficient, it has less than `min(m, n)` linearly
+    ///   independent columns (or rows) to working precision.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is not `1` or `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// // Fit of `y = c0 + c1 x` to the points (0, 1), (1, 3), (2, 5), (3, 7).
+    /// let a = Tensor::from_slice([4, 2], &[1.0f64, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+    /// let y = Tensor::from_slice([4], &[1.0, 3.0, 5.0, 7.0]);
+    ///
+    /// let c = a.lstsq(&y).unwrap();
+    ///
+    /// assert!((c.as_slice()[0] - 1.0).abs() < 1e-12);
+    /// assert!((c.as_slice()[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn lstsq<const N: usize>(&self, b: &Tensor<T, N>) -> Result<Tensor<T, N>, LinalgError> {
+        assert_right_hand_rank::<2, N>();
+
+        let [m, n] = self.metadata.dims();
+        let cols = right_hand_cols(b, m)?;
+
+        let mut dims = b.metadata.dims();
+        dims[0] = n;
+
+        let singular = |pivot| LinalgError::Singular { batch: 0, pivot };
+
+        let x = if m >= n {
+            // x = R^-1 (Q^T b), with the first `n` rows of `Q^T b`.
+            let householder = Householder::new(self.as_slice(), m, n);
+            if let Some(pivot) = householder.rank_deficiency() {
+                return Err(singular(pivot));
+            }
+
+            let mut y = b.as_slice().to_vec();
+            householder.apply_qt(&mut y, cols);
+            y.truncate(n * cols);
+
+            substitute_triangular(&householder.factors[..n * n], n, &mut y, cols, true, false)
+                .map_err(singular)?;
+            y
+        } else {
+            // With A^T = Q R, x = Q (R^-T b) padded with zeros.
+            let transpose = Tensor::make_with(TensorMetadata::new([n, m]), |p| {
+                self.as_slice()[p % m * n + p / m]
+            });
+            let householder = Householder::new(transpose.as_slice(), n, m);
+            if let Some(pivot) = householder.rank_deficiency() {
+                return Err(singular(pivot));
+            }
+
+            let mut z = b.as_slice().to_vec();
+            substitute_triangular(&householder.factors[..m * m], m, &mut z, cols, true, true)
+                .map_err(singular)?;
+
+            z.resize(n * cols, T::ZERO);
+            householder.apply_q(&mut z, cols);
+            z
+        };
+
+        Ok(Tensor::from_boxed_slice(dims, x.into_boxed_slice()))
+    }
+}
+
+#[cfg(test)]
+mod qr_tests {
+    use super::*;
+
+    use crate::linalg::testing::{assert_close, identity, matmul};
+
+    #[test]
+    fn test_qr_reconstruction() {
+        let tall = Tensor::from_slice(
+            [4, 3],
+            &[
+                1.0, -2.0, 3.0, 0.5, 4.0, -1.0, 2.0, 0.0, 1.0, -3.0, 1.0, 2.0,
+            ],
+        );
+        let wide = Tensor::from_slice([2, 3], &[1.0, 2.0, 3.0, -4.0, 5.0, 0.0]);
+
+        for a in [&tall, &wide] {
+            let [m, n] = a.metadata.dims();
+            let k = m.min(n);
+
+            let (q, r) = a.qr();
+
+            assert_eq!(q.shape(), &[m, k]);
+            assert_eq!(r.shape(), &[k, n]);
+            assert_close(matmul(&q, &r, false).as_slice(), a.as_slice());
+            assert_close(matmul(&q, &q, true).as_slice(), &identity(k));
+
+            let (q, r) = a.qr_complete();
+
+            assert_eq!(q.shape(), &[m, m]);
+            assert_eq!(r.shape(), &[m, n]);
+            assert_close(matmul(&q, &r, false).as_slice(), a.as_slice());
+            assert_close(matmul(&q, &q, true).as_slice(), &identity(m));
+
+            for i in 0..m {
+                for j in 0..i.min(n) {
+                    assert_eq!(*r.get(&[i, j]), 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_zero_column() {
+        let a = Tensor::from_slice([3, 2], &[0.0, 1.0, 0.0, 2.0, 0.0, 3.0]);
+
+        let (q, r) = a.qr();
+
+        assert_close(matmul(&q, &r, false).as_slice(), a.as_slice());
+        assert_close(matmul(&q, &q, true).as_slice(), &identity(2));
+    }
+
+    #[test]
+    fn test_lstsq_overdetermined() {
+        let a = Tensor::from_slice([3, 2], &[1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let b = Tensor::from_slice([3, 2], &[1.0, 6.0, 2.0, 5.0, 2.0, 4.0]);
+
+        let x = a.lstsq(&b).unwrap();
+
+        // The normal equations A^T A x = A^T b hold for the least-squares solution.
+        let residual: Vec<f64> = matmul(&a, &x, false)
+            .iter()
+            .zip(b.as_slice())
+            .map(|(ax, b)| ax - b)
+            .collect();
+        let residual = Tensor::from_slice([3, 2], &residual);
+
+        assert_eq!(x.shape(), &[2, 2]);
+        assert_close(matmul(&a, &residual, true).as_slice(), &[0.0; 4]);
+        assert_close(x.as_slice(), &[2.0 / 3.0, 7.0, 0.5, -1.0]);
+    }
+
+    #[test]
+    fn test_lstsq_underdetermined() {
+        let a = Tensor::from_slice([1, 2], &[1.0, 1.0]);
+        let b = Tensor::from_slice([1], &[2.0]);
+
+        let x = a.lstsq(&b).unwrap();
+
+        assert_close(x.as_slice(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_lstsq_errors() {
+        let a = Tensor::from_slice([3, 2], &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+
+        assert_eq!(
+            a.lstsq(&Tensor::new_set([3], 1.0)),
+            Err(LinalgError::Singular { batch: 0, pivot: 1 })
+        );
+        assert_eq!(
+            a.lstsq(&Tensor::new_set([2], 1.0)),
+            Err(LinalgError::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+}
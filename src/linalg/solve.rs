@@ -0,0 +1,342 @@
+use crate::Tensor;
+use crate::assertions::assert_right_hand_rank;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::lu::{Lu, square};
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Solves `A x = b` for the matrix `A` and a vector `b` as `Tensor<T, 1>`, or `A X = B` for
+    /// a matrix `B` as `Tensor<T, 2>`, and returns the solution with the shape of `b`.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    /// - `DimensionMismatch`: The length of `b` along the first axis is not the dimension of the
+    ///   matrix.
+    /// - `Singular`: The matrix is singular.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is not `1` or `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([2, 2], &[2.0f64, 1.0, 1.0, 3.0]);
+    /// let b = Tensor::from_slice([2], &[3.0, 5.0]);
+    ///
+    /// let x = a.solve(&b).unwrap();
+    ///
+    /// assert!((x.as_slice()[0] - 0.8).abs() < 1e-12);
+    /// assert!((x.as_slice()[1] - 1.4).abs() < 1e-12);
+    /// ```
+    pub fn solve<const N: usize>(&self, b: &Tensor<T, N>) -> Result<Tensor<T, N>, LinalgError> {
+        self.lu()?.solve(b)
+    }
+
+    /// Returns the inverse of the matrix.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    /// - `Singular`: The matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{LinalgError, Tensor};
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[4.0f64, 7.0, 2.0, 6.0]);
+    /// let singular = Tensor::from_slice([2, 2], &[1.0, 2.0, 2.0, 4.0]);
+    ///
+    /// let inverse = matrix.inv().unwrap();
+    ///
+    /// assert!((inverse.get(&[0, 0]) - 0.6).abs() < 1e-12);
+    /// assert!((inverse.get(&[1, 0]) + 0.2).abs() < 1e-12);
+    /// assert_eq!(singular.inv(), Err(LinalgError::Singular { batch: 0, pivot: 1 }));
+    /// ```
+    pub fn inv(&self) -> Result<Self, LinalgError> {
+        self.lu()?.inv()
+    }
+
+    /// Returns the determinant of the matrix.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([3, 3], &[2.0f64, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+    ///
+    /// assert!((matrix.det().unwrap() - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn det(&self) -> Result<T, LinalgError> {
+        Ok(self.lu()?.det())
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of
+    /// the matrix, which does not overflow or underflow for large matrices.
+    ///
+    /// The sign is `0` and the logarithm is negative infinity if the determinant is `0`.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    pub fn slogdet(&self) -> Result<(T, T), LinalgError> {
+        Ok(self.lu()?.slogdet())
+    }
+}
+
+impl<T> Tensor<T, 3>
+where
+    T: Float,
+{
+    /// Returns the number of matrices and their dimension, if they are square.
+    fn square_batch(&self) -> Result<(usize, usize), LinalgError> {
+        let [batch, rows, cols] = self.metadata.dims();
+        Ok((batch, square(rows, cols)?))
+    }
+
+    /// Returns the LU factorization of each matrix of the batch.
+    fn lu_batch(&self) -> Result<impl Iterator<Item = Lu<T>>, LinalgError> {
+        let (_, n) = self.square_batch()?;
+
+        Ok(self
+            .as_slice()
+            .chunks_exact(n * n)
+            .map(move |a| Lu::from_values(a, n)))
+    }
+
+    /// Solves `A x = b` for each matrix `A` along the first axis and the vector `b` at the same
+    /// position as `Tensor<T, 2>`, or `A X = B` for the matrix `B` at the same position as
+    /// `Tensor<T, 3>`, and returns the solutions with the shape of `b`.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrices are not square.
+    /// - `DimensionMismatch`: The number of right-hand sides is not the number of matrices, or
+    ///   their length along the second axis is not the dimension of the matrices.
+    /// - `Singular`: The matrix at position `batch` is singular.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is not `2` or `3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([2, 2, 2], &[2.0, 0.0, 0.0, 4.0, 0.0, 1.0, 1.0, 0.0]);
+    /// let b = Tensor::from_slice([2, 2], &[2.0, 2.0, 3.0, 5.0]);
+    ///
+    /// let x = a.solve(&b).unwrap();
+    ///
+    /// assert_eq!(x.as_slice(), &[1.0, 0.5, 5.0, 3.0]);
+    /// ```
+    pub fn solve<const N: usize>(&self, b: &Tensor<T, N>) -> Result<Tensor<T, N>, LinalgError> {
+        assert_right_hand_rank::<3, N>();
+
+        let (batch, n) = self.square_batch()?;
+        let dims = b.metadata.dims();
+
+        if dims[0] != batch {
+            return Err(LinalgError::DimensionMismatch {
+                expected: batch,
+                found: dims[0],
+            });
+        }
+        if dims[1] != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: n,
+                found: dims[1],
+            });
+        }
+
+        let mut x = b.clone();
+        let m = b.metadata.size() / (batch * n);
+
+        for (index, (lu, values)) in self
+            .lu_batch()?
+            .zip(x.as_slice_mut().chunks_exact_mut(n * m))
+            .enumerate()
+        {
+            lu.solve_values(values, m, index)?;
+        }
+
+        Ok(x)
+    }
+
+    /// Returns the inverse of each matrix along the first axis.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrices are not square.
+    /// - `Singular`: The matrix at position `batch` is singular.
+    pub fn inv(&self) -> Result<Self, LinalgError> {
+        let (_, n) = self.square_batch()?;
+
+        let mut x = Tensor::make_with(self.metadata, |p| {
+            if p % (n * n) / n == p % n {
+                T::ONE
+            } else {
+                T::ZERO
+            }
+        });
+
+        for (index, (lu, values)) in self
+            .lu_batch()?
+            .zip(x.as_slice_mut().chunks_exact_mut(n * n))
+            .enumerate()
+        {
+            lu.solve_values(values, n, index)?;
+        }
+
+        Ok(x)
+    }
+
+    /// Returns the determinant of each matrix along the first axis.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrices are not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrices = Tensor::from_slice([2, 2, 2], &[1.0, 2.0, 4.0, 4.0, 2.0, 0.0, 0.0, 2.0]);
+    ///
+    /// let det = matrices.det().unwrap();
+    ///
+    /// assert_eq!(det.as_slice(), &[-4.0, 4.0]);
+    /// ```
+    pub fn det(&self) -> Result<Tensor<T, 1>, LinalgError> {
+        let (batch, _) = self.square_batch()?;
+        let det: Vec<T> = self.lu_batch()?.map(|lu| lu.det()).collect();
+
+        Ok(Tensor::from_slice([batch], &det))
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of
+    /// each matrix along the first axis, as two tensors.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrices are not square.
+    pub fn slogdet(&self) -> Result<(Tensor<T, 1>, Tensor<T, 1>), LinalgError> {
+        let (batch, _) = self.square_batch()?;
+        let (signs, logs): (Vec<T>, Vec<T>) = self.lu_batch()?.map(|lu| lu.slogdet()).unzip();
+
+        Ok((
+            Tensor::from_slice([batch], &signs),
+            Tensor::from_slice([batch], &logs),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod solve_tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_matrix_rhs() {
+        let a = Tensor::from_slice([3, 3], &[4.0, -2.0, 1.0, -2.0, 4.0, -2.0, 1.0, -2.0, 4.0]);
+        let b = Tensor::from_slice([3, 2], &[11.0, 1.0, -16.0, 2.0, 17.0, 3.0]);
+
+        let x = a.solve(&b).unwrap();
+
+        // A x = b for each column.
+        let (a_values, x_values) = (a.as_slice(), x.as_slice());
+        for i in 0..3 {
+            for c in 0..2 {
+                let sum: f64 = (0..3)
+                    .map(|k| a_values[i * 3 + k] * x_values[k * 2 + c])
+                    .sum();
+                assert!((sum - b.get(&[i, c])).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inv_and_det() {
+        let a = Tensor::from_slice([2, 2], &[3.0f32, 1.0, 5.0, 2.0]);
+
+        let inverse = a.inv().unwrap();
+
+        for (x, y) in inverse.iter().zip([2.0, -1.0, -5.0, 3.0]) {
+            assert!((x - y).abs() < 1e-5);
+        }
+        assert!((a.det().unwrap() - 1.0).abs() < 1e-6);
+
+        let (sign, log) = Tensor::from_slice([2, 2], &[0.0, 2.0, 3.0, 0.0])
+            .slogdet()
+            .unwrap();
+
+        assert_eq!(sign, -1.0);
+        assert!((log - 6f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let a = Tensor::from_slice([2, 2], &[1.0, 2.0, 2.0, 4.0]);
+
+        assert_eq!(
+            a.solve(&Tensor::from_slice([3], &[1.0, 2.0, 3.0])),
+            Err(LinalgError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            a.solve(&Tensor::from_slice([2], &[1.0, 2.0])),
+            Err(LinalgError::Singular { batch: 0, pivot: 1 })
+        );
+        assert_eq!(
+            Tensor::new_set([2, 3], 1.0).det(),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_batched() {
+        let a = Tensor::from_slice(
+            [3, 2, 2],
+            &[1.0, 2.0, 3.0, 4.0, 2.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+        );
+        let b = Tensor::from_slice([3, 2, 1], &[5.0, 11.0, 1.0, 1.0, 1.0, 1.0]);
+
+        let det = a.det().unwrap();
+        let (sign, _) = a.slogdet().unwrap();
+
+        assert!((det.as_slice()[0] + 2.0).abs() < 1e-12);
+        assert_eq!(det.as_slice()[1..], [1.0, 0.0]);
+        assert_eq!(sign.as_slice(), &[-1.0, 1.0, 0.0]);
+
+        // The third matrix is singular.
+        assert_eq!(
+            a.solve(&b),
+            Err(LinalgError::Singular { batch: 2, pivot: 1 })
+        );
+        assert_eq!(a.inv(), Err(LinalgError::Singular { batch: 2, pivot: 1 }));
+
+        let a = Tensor::from_slice([2, 2, 2], &a.as_slice()[..8]);
+        let b = Tensor::from_slice([2, 2, 1], &b.as_slice()[..4]);
+
+        let x = a.solve(&b).unwrap();
+        let inverse = a.inv().unwrap();
+
+        assert!((x.get(&[0, 0, 0]) - 1.0).abs() < 1e-12);
+        assert!((x.get(&[0, 1, 0]) - 2.0).abs() < 1e-12);
+        assert_eq!(x.as_slice()[2..], [0.5, 2.0]);
+        assert_eq!(inverse.as_slice()[4..], [0.5, 0.0, 0.0, 2.0]);
+        assert_eq!(
+            a.solve(&Tensor::new_set([3, 2], 1.0)),
+            Err(LinalgError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
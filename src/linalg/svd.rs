@@ -0,0 +1,427 @@
+use crate::Tensor;
+use crate::metadata::TensorMetadata;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::eigh::{MAX_SWEEPS, rotation_tangent};
+
+/// Singular values with the left and right singular vectors, as computed by `jacobi`.
+type Decomposition<T> = (Vec<T>, Vec<T>, Vec<T>);
+
+/// Computes the singular value decomposition of the row-major `m x n` matrix `a`, with `m`
+/// greater than or equal to `n`, with the one-sided Jacobi method.
+///
+/// Returns the singular values in descending order, the row-major `m x n` matrix whose columns
+/// are the corresponding left singular vectors, zero for zero singular values, and the row-major
+/// `n x n` orthogonal matrix whose columns are the right singular vectors.
+fn jacobi<T>(a: &[T], m: usize, n: usize) -> Result<Decomposition<T>, LinalgError>
+where
+    T: Float,
+{
+    let mut w = a.to_vec();
+
+    // Squared norm below which a column is negligible.
+    let negligible = a.iter().fold(T::ZERO, |norm, &x| norm.hypot(x)) * T::EPSILON;
+    let negligible = negligible * negligible;
+    let mut v: Vec<T> = (0..n * n)
+        .map(|p| if p / n == p % n { T::ONE } else { T::ZERO })
+        .collect();
+
+    // Rotates columns `p` and `q` of the row-major matrix `x` with `cols` columns.
+    let rotate = |x: &mut [T], cols: usize, p: usize, q: usize, c: T, s: T| {
+        for row in x.chunks_exact_mut(cols) {
+            let (xp, xq) = (row[p], row[q]);
+            row[p] = c * xp - s * xq;
+            row[q] = s * xp + c * xq;
+        }
+    };
+
+    let mut sweep = 0;
+    loop {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                for row in w.chunks_exact(n) {
+                    alpha += row[p] * row[p];
+                    beta += row[q] * row[q];
+                    gamma += row[p] * row[q];
+                }
+
+                // Columns orthogonal to working precision, or negligible.
+                if gamma == T::ZERO
+                    || gamma.abs() <= T::EPSILON * (alpha * beta).sqrt()
+                    || alpha <= negligible
+                    || beta <= negligible
+                {
+                    continue;
+                }
+                rotated = true;
+
+                let t = rotation_tangent((beta - alpha) / (gamma + gamma));
+                let c = (t * t + T::ONE).sqrt().recip();
+                let s = t * c;
+
+                rotate(&mut w, n, p, q, c, s);
+                rotate(&mut v, n, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+
+        sweep += 1;
+        if sweep == MAX_SWEEPS {
+            return Err(LinalgError::NoConvergence { sweeps: sweep });
+        }
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            w.chunks_exact(n)
+                .fold(T::ZERO, |norm, row| norm.hypot(row[j]))
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    let sigma: Vec<T> = order.iter().map(|&j| norms[j]).collect();
+    let u = (0..m * n)
+        .map(|p| {
+            let j = order[p % n];
+            if norms[j] > T::ZERO {
+                w[p / n * n + j] / norms[j]
+            } else {
+                T::ZERO
+            }
+        })
+        .collect();
+    let v = (0..n * n).map(|p| v[p / n * n + order[p % n]]).collect();
+
+    Ok((sigma, u, v))
+}
+
+/// Returns the first `cols` columns of an orthogonal `rows x rows` matrix whose first columns
+/// are the columns of the row-major `rows x k` matrix `x` for the values of `sigma`, which are in
+/// descending order, greater than `rows * epsilon * sigma[0]`. The other columns complete an
+/// orthonormal basis.
+fn complete<T>(x: &[T], rows: usize, k: usize, sigma: &[T], cols: usize) -> Tensor<T, 2>
+where
+    T: Float,
+{
+    let tolerance = T::from_f64(rows as f64) * T::EPSILON * sigma[0];
+    let r = sigma.iter().take_while(|&&s| s > tolerance).count();
+
+    let basis = if r > 0 && r < cols {
+        let columns = Tensor::make_with(TensorMetadata::new([rows, r]), |p| x[p / r * k + p % r]);
+        Some(columns.qr_complete().0)
+    } else {
+        None
+    };
+
+    Tensor::make_with(TensorMetadata::new([rows, cols]), |p| {
+        let (i, j) = (p / cols, p % cols);
+        match &basis {
+            _ if j < r => x[i * k + j],
+            Some(q) => q.as_slice()[i * rows + j],
+            None if i == j => T::ONE,
+            None => T::ZERO,
+        }
+    })
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Computes the singular value decomposition `A = U diag(s) V^T` of the `m x n` matrix, where
+    /// `U` and `V` are completed to orthogonal matrices if `full`, and have `min(m, n)` columns
+    /// otherwise.
+    fn svd_with(&self, full: bool) -> Result<(Self, Tensor<T, 1>, Self), LinalgError> {
+        let [m, n] = self.metadata.dims();
+        let k = m.min(n);
+        let a = self.as_slice();
+
+        let (sigma, u, vt) = if m >= n {
+            let (sigma, u, v) = jacobi(a, m, n)?;
+            let u = complete(&u, m, n, &sigma, if full { m } else { n });
+            let vt = Tensor::make_with(TensorMetadata::new([n, n]), |p| v[p % n * n + p / n]);
+            (sigma, u, vt)
+        } else {
+            // With A^T = U' diag(s) V'^T, A = V' diag(s) U'^T.
+            let transpose: Vec<T> = (0..n * m).map(|p| a[p % m * n + p / m]).collect();
+            let (sigma, u, v) = jacobi(&transpose, n, m)?;
+
+            let rows = if full { n } else { m };
+            let right = complete(&u, n, m, &sigma, rows);
+            let vt = Tensor::make_with(TensorMetadata::new([rows, n]), |p| {
+                right.as_slice()[p % n * rows + p / n]
+            });
+            (sigma, Tensor::from_slice([m, m], &v), vt)
+        };
+
+        Ok((u, Tensor::from_slice([k], &sigma), vt))
+    }
+
+    /// Computes the thin singular value decomposition `A = U diag(s) V^T` of the `m x n` matrix
+    /// with the one-sided Jacobi method, and returns `U` as `m x k` matrix with orthonormal
+    /// columns, the singular values `s` in descending order, and `V^T` as `k x n` matrix with
+    /// orthonormal rows, where `k` is `min(m, n)`.
+    ///
+    /// # Errors
+    /// - `NoConvergence`: The columns were not orthogonalized to working precision, which may
+    ///   happen for matrices with NaN or infinite values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([3, 2], &[3.0f64, 0.0, 0.0, -4.0, 0.0, 0.0]);
+    ///
+    /// let (u, s, vt) = matrix.svd().unwrap();
+    ///
+    /// assert_eq!(u.shape(), &[3, 2]);
+    /// assert_eq!(s.as_slice(), &[4.0, 3.0]);
+    /// assert_eq!(vt.shape(), &[2, 2]);
+    /// ```
+    pub fn svd(&self) -> Result<(Self, Tensor<T, 1>, Self), LinalgError> {
+        self.svd_with(false)
+    }
+
+    /// Computes the full singular value decomposition `A = U diag(s) V^T` of the `m x n` matrix,
+    /// and returns `U` as `m x m` orthogonal matrix, the `min(m, n)` singular values `s` in
+    /// descending order, and `V^T` as `n x n` orthogonal matrix.
+    ///
+    /// # Errors
+    /// - `NoConvergence`: The columns were not orthogonalized to working precision, which may
+    ///   happen for matrices with NaN or infinite values.
+    pub fn svd_full(&self) -> Result<(Self, Tensor<T, 1>, Self), LinalgError> {
+        self.svd_with(true)
+    }
+
+    /// Returns the singular values of the matrix in descending order.
+    fn singular_values(&self) -> Result<Vec<T>, LinalgError> {
+        let [m, n] = self.metadata.dims();
+        let a = self.as_slice();
+
+        let (sigma, _, _) = if m >= n {
+            jacobi(a, m, n)?
+        } else {
+            let transpose: Vec<T> = (0..n * m).map(|p| a[p % m * n + p / m]).collect();
+            jacobi(&transpose, n, m)?
+        };
+        Ok(sigma)
+    }
+
+    /// Returns the default tolerance of the singular values, `max(m, n) * epsilon * s_max`.
+    fn default_tolerance(&self, sigma: &[T]) -> T {
+        let [m, n] = self.metadata.dims();
+        T::from_f64(m.max(n) as f64) * T::EPSILON * sigma[0]
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the `m x n` matrix, as `n x m` matrix, with
+    /// its singular value decomposition. Singular values not greater than
+    /// `max(m, n) * epsilon * s_max` are treated as zero.
+    ///
+    /// # Errors
+    /// - `NoConvergence`: The singular value decomposition did not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[1.0f64, 2.0, 2.0, 4.0]);
+    ///
+    /// let pinv = matrix.pinv().unwrap();
+    ///
+    /// for (x, y) in pinv.iter().zip([0.04, 0.08, 0.08, 0.16]) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn pinv(&self) -> Result<Self, LinalgError> {
+        let [m, n] = self.metadata.dims();
+        let k = m.min(n);
+
+        let (u, s, vt) = self.svd()?;
+        let (u, s, vt) = (u.as_slice(), s.as_slice(), vt.as_slice());
+        let tolerance = self.default_tolerance(s);
+
+        Ok(Tensor::make_with(TensorMetadata::new([n, m]), |p| {
+            let (i, j) = (p / m, p % m);
+            let mut sum = T::ZERO;
+            for l in 0..k {
+                if s[l] > tolerance {
+                    sum += vt[l * n + i] * u[j * k + l] / s[l];
+                }
+            }
+            sum
+        }))
+    }
+
+    /// Returns the rank of the matrix, the number of singular values greater than `tolerance`,
+    /// or than `max(m, n) * epsilon * s_max` if `None`.
+    ///
+    /// # Errors
+    /// - `NoConvergence`: The singular value decomposition did not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([3, 3], &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
+    ///
+    /// assert_eq!(matrix.matrix_rank(None), Ok(2));
+    /// assert_eq!(matrix.matrix_rank(Some(100.0)), Ok(0));
+    /// ```
+    pub fn matrix_rank(&self, tolerance: Option<T>) -> Result<usize, LinalgError> {
+        let sigma = self.singular_values()?;
+        let tolerance = tolerance.unwrap_or_else(|| self.default_tolerance(&sigma));
+
+        Ok(sigma.iter().filter(|&&s| s > tolerance).count())
+    }
+
+    /// Returns the condition number of the matrix in the 2-norm, the ratio of its greatest and
+    /// least singular values, infinity if the least is `0`.
+    ///
+    /// # Errors
+    /// - `NoConvergence`: The singular value decomposition did not converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[10.0, 0.0, 0.0, -0.5]);
+    ///
+    /// assert_eq!(matrix.cond(), Ok(20.0));
+    /// ```
+    pub fn cond(&self) -> Result<T, LinalgError> {
+        let sigma = self.singular_values()?;
+        let least = sigma[sigma.len() - 1];
+
+        if least == T::ZERO {
+            Ok(T::INFINITY)
+        } else {
+            Ok(sigma[0] / least)
+        }
+    }
+}
+
+#[cfg(test)]
+mod svd_tests {
+    use super::*;
+
+    use crate::linalg::testing::{assert_close, identity, matmul};
+
+    /// Returns `U diag(s) V^T` with the first `k` columns of `U` and rows of `V^T`.
+    fn reconstruct(u: &Tensor<f64, 2>, s: &Tensor<f64, 1>, vt: &Tensor<f64, 2>) -> Vec<f64> {
+        let ([m, uc], [_, n]) = (u.metadata.dims(), vt.metadata.dims());
+        let k = s.metadata.dims()[0];
+        let (u, s, vt) = (u.as_slice(), s.as_slice(), vt.as_slice());
+
+        (0..m * n)
+            .map(|p| {
+                (0..k)
+                    .map(|l| u[p / n * uc + l] * s[l] * vt[l * n + p % n])
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_svd_reconstruction() {
+        let tall = Tensor::from_slice(
+            [4, 3],
+            &[
+                1.0, -2.0, 3.0, 0.5, 4.0, -1.0, 2.0, 0.0, 1.0, -3.0, 1.0, 2.0,
+            ],
+        );
+        let wide = Tensor::from_slice([2, 3], &[1.0, 2.0, 3.0, -4.0, 5.0, 0.0]);
+        // Rank 1, with zero singular values.
+        let deficient = Tensor::from_slice([3, 3], &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0]);
+        let deficient_wide = Tensor::from_slice([2, 4], &[1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0]);
+
+        // Ill-conditioned Hilbert matrix.
+        let hilbert = Tensor::make_with(TensorMetadata::new([6, 6]), |p| {
+            1.0 / ((p / 6 + p % 6 + 1) as f64)
+        });
+
+        for a in [&tall, &wide, &deficient, &deficient_wide, &hilbert] {
+            let [m, n] = a.metadata.dims();
+            let k = m.min(n);
+
+            let (u, s, vt) = a.svd().unwrap();
+
+            assert_eq!(
+                (u.shape(), s.shape(), vt.shape()),
+                (&[m, k][..], &[k][..], &[k, n][..])
+            );
+            assert!(s.as_slice().windows(2).all(|s| s[0] >= s[1]));
+            assert_close(&reconstruct(&u, &s, &vt), a.as_slice());
+            assert_close(matmul(&u, &u, true).as_slice(), &identity(k));
+
+            let (u, s, vt) = a.svd_full().unwrap();
+
+            assert_eq!((u.shape(), vt.shape()), (&[m, m][..], &[n, n][..]));
+            assert_close(&reconstruct(&u, &s, &vt), a.as_slice());
+            assert_close(matmul(&u, &u, true).as_slice(), &identity(m));
+
+            let v = Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+                vt.as_slice()[p % n * n + p / n]
+            });
+            assert_close(matmul(&v, &v, true).as_slice(), &identity(n));
+        }
+    }
+
+    #[test]
+    fn test_svd_f32() {
+        let a = Tensor::from_slice([2, 2], &[3.0f32, 0.0, 4.0, 5.0]);
+
+        let (_, s, _) = a.svd().unwrap();
+
+        // Singular values of this matrix are sqrt(45) and sqrt(5).
+        assert!((s.as_slice()[0] - 45f32.sqrt()).abs() < 1e-5);
+        assert!((s.as_slice()[1] - 5f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_pinv_penrose_conditions() {
+        let a = Tensor::from_slice(
+            [3, 4],
+            &[1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 0.0, 1.0, 0.0, 1.0],
+        );
+
+        let pinv = a.pinv().unwrap();
+
+        assert_eq!(pinv.shape(), &[4, 3]);
+
+        // A A+ A = A and A+ A A+ = A+.
+        let a_pinv = matmul(&a, &pinv, false);
+        let pinv_a = matmul(&pinv, &a, false);
+
+        assert_close(matmul(&a_pinv, &a, false).as_slice(), a.as_slice());
+        assert_close(matmul(&pinv_a, &pinv, false).as_slice(), pinv.as_slice());
+    }
+
+    #[test]
+    fn test_rank_and_cond() {
+        let zero = Tensor::new_set([2, 3], 0.0);
+        let identity = Tensor::from_slice([2, 2], &[1.0, 0.0, 0.0, 1.0]);
+
+        assert_eq!(zero.matrix_rank(None), Ok(0));
+        assert_eq!(zero.cond(), Ok(f64::INFINITY));
+        assert_eq!(zero.pinv().unwrap().as_slice(), &[0.0; 6]);
+        assert_eq!(identity.matrix_rank(None), Ok(2));
+        assert_eq!(identity.cond(), Ok(1.0));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::Tensor;
+use crate::assertions::assert_right_hand_rank;
+use crate::num::Float;
+
+use super::LinalgError;
+use super::lu::square;
+
+/// Returns the number of columns of the right-hand side `b`, a vector or a matrix, if its length
+/// along the first axis is `rows`.
+pub(super) fn right_hand_cols<T, const N: usize>(
+    b: &Tensor<T, N>,
+    rows: usize,
+) -> Result<usize, LinalgError> {
+    let found = b.metadata.dims()[0];
+
+    if found == rows {
+        Ok(b.metadata.size() / rows)
+    } else {
+        Err(LinalgError::DimensionMismatch {
+            expected: rows,
+            found,
+        })
+    }
+}
+
+/// Solves in-place `op(A) X = B` for the row-major `n x m` matrix `b`, where `op(A)` is `A` or
+/// its transpose if `transpose`, and only the upper (if `upper`) or lower triangle of the
+/// row-major `n x n` matrix `a` is read.
+///
+/// Returns the first row whose diagonal value is `0`, if any, in which case `b` is unspecified.
+pub(super) fn substitute_triangular<T>(
+    a: &[T],
+    n: usize,
+    b: &mut [T],
+    m: usize,
+    upper: bool,
+    transpose: bool,
+) -> Result<(), usize>
+where
+    T: Float,
+{
+    // Element (i, k) of `op(A)`.
+    let at = |i: usize, k: usize| {
+        if transpose {
+            a[k * n + i]
+        } else {
+            a[i * n + k]
+        }
+    };
+
+    let mut step = 0;
+    while step < n {
+        // Backward substitution if `op(A)` is upper triangular, forward otherwise.
+        let i = if upper != transpose {
+            n - 1 - step
+        } else {
+            step
+        };
+        let (from, to) = if upper != transpose {
+            (i + 1, n)
+        } else {
+            (0, i)
+        };
+
+        let mut k = from;
+        while k < to {
+            let u = at(i, k);
+            if u != T::ZERO {
+                let mut c = 0;
+                while c < m {
+                    let x = b[k * m + c];
+                    b[i * m + c] -= u * x;
+                    c += 1;
+                }
+            }
+            k += 1;
+        }
+
+        let d = at(i, i);
+        if d == T::ZERO {
+            return Err(i);
+        }
+
+        let mut c = 0;
+        while c < m {
+            b[i * m + c] /= d;
+            c += 1;
+        }
+        step += 1;
+    }
+
+    Ok(())
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Float,
+{
+    /// Solves `op(A) x = b` for the triangular matrix `A` and a vector `b` as `Tensor<T, 1>`, or
+    /// `op(A) X = B` for a matrix `B` as `Tensor<T, 2>`, and returns the solution with the shape
+    /// of `b`. `op(A)` is the transpose of `A` if `transpose` is `true`, and `A` otherwise.
+    ///
+    /// Only the upper triangle of `A` is read if `upper` is `true`, and the lower triangle
+    /// otherwise.
+    ///
+    /// # Errors
+    /// - `NotSquare`: The matrix is not square.
+    /// - `DimensionMismatch`: The length of `b` along the first axis is not the dimension of the
+    ///   matrix.
+    /// - `Singular`: A value on the diagonal of the matrix is `0`.
+    ///
+    /// # Panics
+    /// This method will panic if `N` is not `1` or `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// // The lower triangle is ignored.
+    /// let a = Tensor::from_slice([2, 2], &[2.0, 1.0, 9.0, 4.0]);
+    /// let b = Tensor::from_slice([2], &[4.0, 8.0]);
+    ///
+    /// let x = a.solve_triangular(&b, true, false).unwrap();
+    /// let y = a.solve_triangular(&b, true, true).unwrap();
+    ///
+    /// assert_eq!(x.as_slice(), &[1.0, 2.0]);
+    /// assert_eq!(y.as_slice(), &[2.0, 1.5]);
+    /// ```
+    pub fn solve_triangular<const N: usize>(
+        &self,
+        b: &Tensor<T, N>,
+        upper: bool,
+        transpose: bool,
+    ) -> Result<Tensor<T, N>, LinalgError> {
+        assert_right_hand_rank::<2, N>();
+
+        let [rows, cols] = self.metadata.dims();
+        let n = square(rows, cols)?;
+        let m = right_hand_cols(b, n)?;
+
+        let mut x = b.clone();
+        substitute_triangular(self.as_slice(), n, x.as_slice_mut(), m, upper, transpose)
+            .map_err(|pivot| LinalgError::Singular { batch: 0, pivot })?;
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod triangular_tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_triangular() {
+        let a = Tensor::from_slice([3, 3], &[2.0, 0.0, 0.0, 1.0, 4.0, 0.0, -1.0, 2.0, 0.5]);
+        let b = Tensor::from_slice([3, 2], &[2.0, 4.0, 5.0, 6.0, 1.0, 3.5]);
+
+        // Lower: 2 x0 = b0, x0 + 4 x1 = b1, -x0 + 2 x1 + 0.5 x2 = b2.
+        let x = a.solve_triangular(&b, false, false).unwrap();
+
+        assert_eq!(x.as_slice(), &[1.0, 2.0, 1.0, 1.0, 0.0, 7.0]);
+
+        // Transposed lower: 2 y0 + y1 - y2 = b0, 4 y1 + 2 y2 = b1, 0.5 y2 = b2.
+        let y = a.solve_triangular(&b, false, true).unwrap();
+
+        assert_eq!(y.as_slice(), &[1.875, 6.5, 0.25, -2.0, 2.0, 7.0]);
+    }
+
+    #[test]
+    fn test_solve_triangular_errors() {
+        let a = Tensor::from_slice([2, 2], &[1.0, 2.0, 3.0, 0.0]);
+
+        assert_eq!(
+            a.solve_triangular(&Tensor::new_set([2], 1.0), true, false),
+            Err(LinalgError::Singular { batch: 0, pivot: 1 })
+        );
+        assert_eq!(
+            a.solve_triangular(&Tensor::new_set([3], 1.0), false, false),
+            Err(LinalgError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
@@ -11,6 +11,7 @@ pub enum MemoryError {
 #[derive(Clone, Copy)]
 pub enum OnError {
     Panic,
+    #[allow(dead_code)]
     ReturnErr,
 }
 
@@ -160,7 +160,7 @@ impl<T> UnmanagedPointer<T> {
     #[must_use]
     #[inline(always)]
     pub unsafe fn from_boxed_slice(slice: Box<[T]>) -> Self {
-        debug_assert!(slice.len() > 0);
+        debug_assert!(!slice.is_empty());
 
         UnmanagedPointer {
             ptr: ManuallyDrop::new(slice).as_mut_ptr(),
@@ -348,6 +348,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     #[must_use]
     #[inline(always)]
+    #[allow(dead_code)]
     pub const unsafe fn as_ref(&self) -> &T {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -406,6 +407,7 @@ impl<T> UnmanagedPointer<T> {
     /// Returns the base pointer as a pointer of type `C`.
     #[must_use]
     #[inline(always)]
+    #[allow(dead_code)]
     pub const fn cast<C>(&self) -> *const C {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -416,6 +418,7 @@ impl<T> UnmanagedPointer<T> {
     /// Returns the base pointer as a mutable pointer of type `C`.
     #[must_use]
     #[inline(always)]
+    #[allow(dead_code)]
     pub const fn cast_mut<C>(&mut self) -> *mut C {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -425,6 +428,7 @@ impl<T> UnmanagedPointer<T> {
 
     /// Increments the pointer's offset by `t_strides` as strides of `T`.
     #[inline(always)]
+    #[allow(dead_code)]
     pub(crate) const unsafe fn increment(&mut self, t_strides: usize) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -434,6 +438,7 @@ impl<T> UnmanagedPointer<T> {
 
     /// Decrements the pointer's offset by `t_strides` as strides of `T`.
     #[inline(always)]
+    #[allow(dead_code)]
     pub(crate) const unsafe fn decrement(&mut self, t_strides: usize) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -540,6 +545,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     /// _O_(n) where `n` is the `count` of type `T`.
     #[inline(always)]
+    #[allow(dead_code)]
     pub const unsafe fn memset_zero(&mut self, count: usize) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -632,6 +638,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     /// _O_(1).
     #[inline(always)]
+    #[allow(dead_code)]
     pub const unsafe fn read_for_ownership(&mut self, offset: usize) -> T {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -652,6 +659,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     /// _O_(n) where `n` is the number (`count`) of the elements to be shifted.
     #[inline(always)]
+    #[allow(dead_code)]
     pub const unsafe fn shift_left(&mut self, offset: usize, count: usize) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -682,6 +690,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     /// _O_(1).
     #[inline(always)]
+    #[allow(dead_code)]
     pub const unsafe fn memmove_one(&mut self, src_offset: usize, dst_offset: usize) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -811,7 +820,7 @@ impl<T> UnmanagedPointer<T> {
     /// # Time Complexity
     ///
     /// _O_(n) where `n` is the number (`count`) of values to be cloned.
-    #[must_use]
+    #[must_use = "the clone owns memory that must be released"]
     pub unsafe fn make_clone(&self, count: usize, on_err: OnError) -> Result<Self, MemoryError>
     where
         T: Clone,
@@ -840,6 +849,55 @@ impl<T> UnmanagedPointer<T> {
         }
     }
 
+    /// Creates new instance with `count` values, initialized in order with the values returned
+    /// by `f` for the offset of each value.
+    ///
+    /// This call is **unwind-safe**. If `f` panics, the initialized values are dropped and the
+    /// allocated memory space is released.
+    ///
+    /// # Safety
+    ///
+    /// - `T` can't be ZST.
+    ///
+    /// - `count` must be greater than `0`.
+    ///
+    /// # Time Complexity
+    ///
+    /// _O_(n) where `n` is the number (`count`) of values to be initialized.
+    pub unsafe fn make_with<F>(count: usize, on_err: OnError, mut f: F) -> Result<Self, MemoryError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut instance = Self::new();
+
+        unsafe {
+            // Checked, because the size of T * count might overflow.
+            let layout = instance.layout_of(count, on_err)?;
+
+            instance.acquire(layout, on_err)?;
+
+            let mut unwind_guard = OnDrop::set_on(
+                (instance.duplicate(), 0, layout),
+                |(instance, initialized, layout)| {
+                    instance.drop_in_place(*initialized);
+                    instance.release(*layout);
+                },
+            );
+
+            let ptr = instance.ptr;
+
+            while unwind_guard.arg.1 < count {
+                let i = unwind_guard.arg.1;
+                ptr.add(i).write(f(i));
+                unwind_guard.arg.1 += 1;
+            }
+
+            unwind_guard.set_off();
+        }
+
+        Ok(instance)
+    }
+
     /// Compares the values in the memory space pointed to by this pointer with the values in a
     /// memory space pointed to by other pointer.
     ///
@@ -938,6 +996,7 @@ impl<T> UnmanagedPointer<T> {
     /// _O_(n) where `n` is the number (`count`) of the elements to be dropped.
     ///
     #[inline(always)]
+    #[allow(dead_code)]
     pub unsafe fn drop_range(&mut self, range: Range<usize>) {
         #[cfg(debug_assertions)]
         debug_assert_not_null(self);
@@ -966,6 +1025,7 @@ impl<T> UnmanagedPointer<T> {
     ///
     /// This function is available in debug-mode only.
     #[must_use]
+    #[allow(dead_code)]
     pub(crate) const fn debug_is_null(&self) -> bool {
         self.ptr.is_null()
     }
@@ -1409,6 +1469,41 @@ mod tests_unmanaged_ptr {
         }
     }
 
+    #[test]
+    fn test_unmanaged_ptr_make_with() {
+        unsafe {
+            let mut instance = match UnmanagedPointer::make_with(4, OnError::Panic, |i| i * 2) {
+                Ok(instance) => instance,
+                Err(_) => unreachable!(),
+            };
+
+            assert_eq!(instance.as_slice(4), &[0, 2, 4, 6]);
+
+            let layout = instance.layout_unchecked_of(4);
+            instance.release(layout);
+        }
+    }
+
+    #[test]
+    fn test_unmanaged_ptr_make_with_unwind() {
+        let drop_count = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&drop_count);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || unsafe {
+            UnmanagedPointer::make_with(10, OnError::Panic, |i| {
+                if i == 5 {
+                    panic!("Initialization of offset {i} panicked");
+                }
+                DropCounter {
+                    count: Rc::clone(&counter),
+                }
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(*drop_count.borrow(), 5);
+    }
+
     struct PanicOnClone {
         id: usize,
         panic_on: usize,
@@ -73,6 +73,53 @@ impl<const R: usize> TensorMetadata<R> {
         &self.dims
     }
 
+    /// Returns a copy of the dimensions' array.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn dims(&self) -> [usize; R] {
+        self.dims
+    }
+
+    /// Returns the strides of the dimensions.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Splits the dimensions at `axis` and returns the product of the dimensions before `axis`,
+    /// the dimension of `axis` and the product of the dimensions after `axis`.
+    ///
+    /// In row-major order, the value at coordinate `k` along `axis` in lane `(o, i)` is at
+    /// offset `(o * len + k) * inner + i`.
+    ///
+    /// `axis` must be less than `R`.
+    #[must_use]
+    #[inline]
+    pub(crate) const fn split_at_axis(&self, axis: usize) -> (usize, usize, usize) {
+        let len = self.dims[axis];
+        let inner = self.strides[axis];
+        (self.size / (len * inner), len, inner)
+    }
+
+    /// Returns the dimensions without the dimension of `axis`.
+    ///
+    /// `N` must be `R - 1`, and `axis` must be less than `R`.
+    #[must_use]
+    pub(crate) const fn remove_axis<const N: usize>(&self, axis: usize) -> [usize; N] {
+        let mut dims = [0; N];
+        let mut i = 0;
+        while i < N {
+            dims[i] = if i < axis {
+                self.dims[i]
+            } else {
+                self.dims[i + 1]
+            };
+            i += 1;
+        }
+        dims
+    }
+
     /// Sets the new dimensions and computes their strides.
     ///
     /// This method will panic if current size does not match the product of the dimensions.
@@ -137,6 +184,23 @@ impl<const R: usize> TensorMetadata<R> {
         }
     }
 
+    /// Computes and returns the multidimensional index of the linear `offset` in the data
+    /// buffer.
+    ///
+    /// `offset` must be less than the size.
+    #[must_use]
+    pub(crate) const fn index_of(&self, offset: usize) -> [usize; R] {
+        let mut index = [0; R];
+        let mut rem = offset;
+        let mut i = 0;
+        while i < R {
+            index[i] = rem / self.strides[i];
+            rem %= self.strides[i];
+            i += 1;
+        }
+        index
+    }
+
     /// Compares the `dimensions` of two instances element-wise from right to left.
     #[must_use]
     pub(crate) const fn cmp_dims_eq(&self, other: &Self) -> bool {
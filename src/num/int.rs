@@ -0,0 +1,72 @@
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Trait for the primitive integer types.
+///
+/// The methods have the same semantics as the inherent methods of the primitive types with the
+/// same names.
+pub trait Integer:
+    Copy
+    + Default
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn wrapping_div(self, other: Self) -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn saturating_div(self, other: Self) -> Self;
+    fn rem_euclid(self, other: Self) -> Self;
+    fn div_euclid(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                impl_integer!(@checked $t; checked_add checked_sub checked_mul checked_div);
+
+                impl_integer!(@binary $t; wrapping_add wrapping_sub wrapping_mul wrapping_div
+                    saturating_add saturating_sub saturating_mul saturating_div rem_euclid div_euclid);
+            }
+        )*
+    };
+    (@checked $t:ty; $($name:ident)*) => {
+        $(
+            #[inline(always)]
+            fn $name(self, other: Self) -> Option<Self> {
+                <$t>::$name(self, other)
+            }
+        )*
+    };
+    (@binary $t:ty; $($name:ident)*) => {
+        $(
+            #[inline(always)]
+            fn $name(self, other: Self) -> Self {
+                <$t>::$name(self, other)
+            }
+        )*
+    };
+}
+
+impl_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
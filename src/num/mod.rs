@@ -0,0 +1,13 @@
+mod float;
+mod int;
+mod order;
+mod sum;
+
+// Public exports
+pub use float::Float;
+pub use int::Integer;
+pub use order::TotalOrder;
+pub use sum::Summation;
+
+// Crate exports
+pub(crate) use sum::Accumulator;
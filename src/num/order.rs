@@ -0,0 +1,65 @@
+use core::cmp::Ordering;
+
+/// Trait for types with a total order used by the sorting and searching operations.
+///
+/// For the integer types, the order is the one of `Ord`. For the floating-point types, it is the
+/// one of the inherent `total_cmp`: `-0.0` is less than `0.0`, positive NaN is greater than any
+/// other value, including positive infinity, and negative NaN is less than any other value.
+pub trait TotalOrder: Copy {
+    /// Compares `self` with `other` in the total order.
+    fn total_order(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_total_order_int {
+    ($($t:ty)*) => {
+        $(
+            impl TotalOrder for $t {
+                #[inline(always)]
+                fn total_order(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_total_order_float {
+    ($($t:ty)*) => {
+        $(
+            impl TotalOrder for $t {
+                #[inline(always)]
+                fn total_order(&self, other: &Self) -> Ordering {
+                    self.total_cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order_int!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_total_order_float!(f32 f64);
+
+#[cfg(test)]
+mod order_tests {
+    use super::*;
+
+    #[test]
+    fn test_float_total_order() {
+        assert_eq!(1.0.total_order(&2.0), Ordering::Less);
+        assert_eq!((-0.0f32).total_order(&0.0), Ordering::Less);
+        assert_eq!(f64::NAN.total_order(&f64::INFINITY), Ordering::Greater);
+        assert_eq!((-f64::NAN).total_order(&f64::NEG_INFINITY), Ordering::Less);
+        assert_eq!(f64::NAN.total_order(&-f64::NAN), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_float_total_order_matches_total_cmp() {
+        let values = [0.0, -0.0, -f64::NAN, f64::NAN, 1.0, f64::NEG_INFINITY];
+
+        for a in values {
+            for b in values {
+                assert_eq!(a.total_order(&b), a.total_cmp(&b));
+            }
+        }
+    }
+}
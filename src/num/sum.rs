@@ -0,0 +1,218 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Strategy to sum floating-point values in the reductions of tensors.
+/// The following strategies are defined:
+/// - `Naive`: Running sum. The error grows linearly with the number of values.
+/// - `Pairwise`: Sums of blocks of values are added in pairs as a balanced tree. The error grows
+///   logarithmically with the number of values, for almost the cost of `Naive`.
+/// - `KahanBabuska`: Running sum with compensation of the rounding error of each addition, as
+///   improved by Neumaier. The error does not depend on the number of values, at about four
+///   times the cost of `Naive`.
+///
+/// Float reductions without an explicit strategy use the global default, which is `Naive` until
+/// changed with [`Summation::set_global`]. The reductions generic over the element type, such as
+/// `dot_product`, `trace` and `tensordot`, add the values naively in that type instead, and
+/// [`dot_product_with`](crate::Tensor::dot_product_with) takes a strategy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Summation {
+    Naive,
+    Pairwise,
+    KahanBabuska,
+}
+
+/// The global default strategy, as the discriminant of `Summation`.
+static GLOBAL: AtomicU8 = AtomicU8::new(Summation::Naive as u8);
+
+impl Summation {
+    /// Returns the global default strategy.
+    pub fn global() -> Self {
+        match GLOBAL.load(Ordering::Relaxed) {
+            0 => Self::Naive,
+            1 => Self::Pairwise,
+            _ => Self::KahanBabuska,
+        }
+    }
+
+    /// Sets the global default strategy, used by the reductions called afterwards from any
+    /// thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Summation;
+    ///
+    /// Summation::set_global(Summation::KahanBabuska);
+    ///
+    /// assert_eq!(Summation::global(), Summation::KahanBabuska);
+    /// ```
+    pub fn set_global(summation: Self) {
+        GLOBAL.store(summation as u8, Ordering::Relaxed);
+    }
+}
+
+/// Number of values summed naively in each leaf of the pairwise summation tree.
+const BLOCK: usize = 128;
+
+/// Streaming sum of `f64` values with a summation strategy.
+///
+/// The pairwise strategy keeps the sums of complete subtrees of blocks in a stack, where the
+/// sum at `levels[l]` covers `2^l` blocks and bit `l` of `occupied` tells if it is set. Adding a
+/// block works as incrementing a binary counter, sums of equal levels are carried up.
+pub(crate) struct Accumulator {
+    summation: Summation,
+    sum: f64,
+    compensation: f64,
+    count: usize,
+    levels: [f64; usize::BITS as usize],
+    occupied: usize,
+}
+
+impl Accumulator {
+    /// Creates an empty accumulator with the provided strategy.
+    pub(crate) const fn new(summation: Summation) -> Self {
+        Self {
+            summation,
+            sum: 0.0,
+            compensation: 0.0,
+            count: 0,
+            levels: [0.0; usize::BITS as usize],
+            occupied: 0,
+        }
+    }
+
+    /// Empties the accumulator, keeping its strategy. The sums of the stack of subtrees are
+    /// ignored until set again, so they are not cleared.
+    #[inline(always)]
+    pub(crate) fn reset(&mut self) {
+        self.sum = 0.0;
+        self.compensation = 0.0;
+        self.count = 0;
+        self.occupied = 0;
+    }
+
+    /// Adds `value` to the sum.
+    #[inline(always)]
+    pub(crate) fn add(&mut self, value: f64) {
+        match self.summation {
+            Summation::Naive => self.sum += value,
+            Summation::Pairwise => {
+                self.sum += value;
+                self.count += 1;
+
+                if self.count == BLOCK {
+                    self.carry(self.sum);
+                    self.sum = 0.0;
+                    self.count = 0;
+                }
+            }
+            Summation::KahanBabuska => {
+                let t = self.sum + value;
+
+                if self.sum.abs() >= value.abs() {
+                    self.compensation += (self.sum - t) + value;
+                } else {
+                    self.compensation += (value - t) + self.sum;
+                }
+
+                self.sum = t;
+            }
+        }
+    }
+
+    /// Pushes the sum of a complete block to the stack of subtrees.
+    fn carry(&mut self, mut sum: f64) {
+        let mut level = 0;
+        while self.occupied & (1 << level) != 0 {
+            sum += self.levels[level];
+            self.occupied &= !(1 << level);
+            level += 1;
+        }
+        self.levels[level] = sum;
+        self.occupied |= 1 << level;
+    }
+
+    /// Returns the sum of the added values.
+    pub(crate) fn sum(&self) -> f64 {
+        match self.summation {
+            Summation::Naive => self.sum,
+            Summation::Pairwise => {
+                // Smaller subtrees are added first.
+                let mut sum = self.sum;
+                let mut level = 0;
+                while level < self.levels.len() {
+                    if self.occupied & (1 << level) != 0 {
+                        sum += self.levels[level];
+                    }
+                    level += 1;
+                }
+                sum
+            }
+            Summation::KahanBabuska => self.sum + self.compensation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod sum_tests {
+    use super::*;
+
+    fn sum_of(values: impl Iterator<Item = f64>, summation: Summation) -> f64 {
+        let mut acc = Accumulator::new(summation);
+        values.for_each(|x| acc.add(x));
+        acc.sum()
+    }
+
+    #[test]
+    fn test_cancellation() {
+        // The exact sum is 2, the naive sum loses both ones to the large values.
+        let values = [1.0, 1e100, 1.0, -1e100];
+
+        assert_eq!(sum_of(values.into_iter(), Summation::Naive), 0.0);
+        assert_eq!(sum_of(values.into_iter(), Summation::KahanBabuska), 2.0);
+    }
+
+    #[test]
+    fn test_error_bound() {
+        // The exact sum of n copies of 0.1 rounds to n * 0.1 for a power of two n.
+        let n = 1 << 20;
+        let exact = n as f64 * 0.1;
+        let values = || core::iter::repeat_n(0.1, n);
+
+        let naive = (sum_of(values(), Summation::Naive) - exact).abs();
+        let pairwise = (sum_of(values(), Summation::Pairwise) - exact).abs();
+        let kahan = (sum_of(values(), Summation::KahanBabuska) - exact).abs();
+
+        // Bounds relative to the sum: n * eps for naive, log2(n) * eps for pairwise and 2 * eps
+        // for Kahan-Babuska.
+        let eps = f64::EPSILON * exact;
+        assert!(naive > 20.0 * eps);
+        assert!(pairwise <= 20.0 * eps);
+        assert!(kahan <= 2.0 * eps);
+    }
+
+    #[test]
+    fn test_reset() {
+        for summation in [
+            Summation::Naive,
+            Summation::Pairwise,
+            Summation::KahanBabuska,
+        ] {
+            let mut acc = Accumulator::new(summation);
+            (0..3 * BLOCK).for_each(|x| acc.add(x as f64 + 0.1));
+
+            acc.reset();
+            (1..=BLOCK + 1).for_each(|x| acc.add(x as f64));
+
+            assert_eq!(acc.sum(), ((BLOCK + 1) * (BLOCK + 2) / 2) as f64);
+        }
+    }
+
+    #[test]
+    fn test_pairwise_partial_blocks() {
+        for n in [0, 1, BLOCK - 1, BLOCK, 3 * BLOCK + 7] {
+            let sum = sum_of((1..=n).map(|x| x as f64), Summation::Pairwise);
+
+            assert_eq!(sum, (n * (n + 1) / 2) as f64);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::Tensor;
+use crate::assertions::assert_matrix_rank;
+use crate::metadata::TensorMetadata;
+
+/// Returns `true` if the value at `(row, col)` lies within `lower` diagonals below and `upper`
+/// diagonals above the main diagonal.
+#[inline(always)]
+fn in_band(row: usize, col: usize, lower: isize, upper: isize) -> bool {
+    let offset = col as isize - row as isize;
+    -lower <= offset && offset <= upper
+}
+
+/// Converts an optional number of diagonals to a bound on the offsets, `None` being unbounded.
+#[inline(always)]
+const fn band_bound(diagonals: Option<usize>) -> isize {
+    match diagonals {
+        Some(n) if n < isize::MAX as usize => n as isize,
+        _ => isize::MAX,
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + Default,
+{
+    /// Returns the dimensions of the matrices in the last two axes.
+    #[inline(always)]
+    const fn matrix_dims(&self) -> (usize, usize) {
+        assert_matrix_rank::<R>();
+
+        let dims = self.metadata.dims();
+        (dims[R - 2], dims[R - 1])
+    }
+
+    /// Returns the matrices in the last two axes with the values outside the band between the
+    /// offsets `lower` and `upper` set to `T::default()`.
+    fn band(&self, lower: isize, upper: isize) -> Self {
+        let (rows, cols) = self.matrix_dims();
+        let a = self.as_slice();
+
+        Tensor::make_with(self.metadata, |p| {
+            let (row, col) = (p / cols % rows, p % cols);
+            if in_band(row, col, lower, upper) {
+                a[p]
+            } else {
+                T::default()
+            }
+        })
+    }
+
+    /// Sets the values outside the band between the offsets `lower` and `upper` of the matrices
+    /// in the last two axes to `T::default()`.
+    fn band_inplace(&mut self, lower: isize, upper: isize) {
+        let (rows, cols) = self.matrix_dims();
+
+        for (p, value) in self.iter_mut().enumerate() {
+            if !in_band(p / cols % rows, p % cols, lower, upper) {
+                *value = T::default();
+            }
+        }
+    }
+
+    /// Returns the lower triangles of the matrices in the last two axes as new `Tensor<T, R>`,
+    /// keeping the values on and below the diagonal at offset `k` and setting the others to
+    /// `T::default()`.
+    ///
+    /// The diagonal at offset `k` is above the main diagonal if `k` is positive and below it if
+    /// negative.
+    ///
+    /// # Panics
+    /// This method will panic if `R` is less than `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2, 2], &[1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// assert_eq!(tensor.tril(0).as_slice(), &[1, 0, 3, 4, 5, 0, 7, 8]);
+    /// assert_eq!(tensor.tril(-1).as_slice(), &[0, 0, 3, 0, 0, 0, 7, 0]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Self {
+        self.band(isize::MAX, k)
+    }
+
+    /// Keeps the values on and below the diagonal at offset `k` of the matrices in the last two
+    /// axes and sets the others to `T::default()` in-place.
+    ///
+    /// # Panics
+    /// This method will panic if `R` is less than `2`.
+    pub fn tril_inplace(&mut self, k: isize) {
+        self.band_inplace(isize::MAX, k);
+    }
+
+    /// Returns the upper triangles of the matrices in the last two axes as new `Tensor<T, R>`,
+    /// keeping the values on and above the diagonal at offset `k` and setting the others to
+    /// `T::default()`.
+    ///
+    /// # Panics
+    /// This method will panic if `R` is less than `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(tensor.triu(0).as_slice(), &[1, 2, 3, 0, 5, 6]);
+    /// assert_eq!(tensor.triu(1).as_slice(), &[0, 2, 3, 0, 0, 6]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Self {
+        self.band(k.saturating_neg(), isize::MAX)
+    }
+
+    /// Keeps the values on and above the diagonal at offset `k` of the matrices in the last two
+    /// axes and sets the others to `T::default()` in-place.
+    ///
+    /// # Panics
+    /// This method will panic if `R` is less than `2`.
+    pub fn triu_inplace(&mut self, k: isize) {
+        self.band_inplace(k.saturating_neg(), isize::MAX);
+    }
+
+    /// Returns the bands of the matrices in the last two axes as new `Tensor<T, R>`, keeping the
+    /// values on the main diagonal, on the `lower` diagonals below it and on the `upper`
+    /// diagonals above it, and setting the others to `T::default()`.
+    ///
+    /// `None` keeps the whole triangle on that side.
+    ///
+    /// # Panics
+    /// This method will panic if `R` is less than `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([3, 3], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(
+    ///     tensor.band_part(Some(1), Some(0)).as_slice(),
+    ///     &[1, 0, 0, 4, 5, 0, 0, 8, 9]
+    /// );
+    /// assert_eq!(
+    ///     tensor.band_part(None, Some(1)).as_slice(),
+    ///     &[1, 2, 0, 4, 5, 6, 7, 8, 9]
+    /// );
+    /// ```
+    pub fn band_part(&self, lower: Option<usize>, upper: Option<usize>) -> Self {
+        self.band(band_bound(lower), band_bound(upper))
+    }
+}
+
+impl Tensor<usize, 2> {
+    /// Returns the indices of the values of a `rows x cols` matrix on and below the diagonal
+    /// at offset `k` as new `Tensor<usize, 2>` of `[row, col]` pairs, in row-major order.
+    ///
+    /// Returns `None` if there are no such values, since tensors can't be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let indices = Tensor::tril_indices(2, 3, 0).unwrap();
+    ///
+    /// assert_eq!(indices.shape(), &[3, 2]);
+    /// assert_eq!(indices.as_slice(), &[0, 0, 1, 0, 1, 1]);
+    /// assert_eq!(Tensor::tril_indices(2, 3, -2), None);
+    /// ```
+    pub fn tril_indices(rows: usize, cols: usize, k: isize) -> Option<Self> {
+        band_indices(rows, cols, isize::MAX, k)
+    }
+
+    /// Returns the indices of the values of a `rows x cols` matrix on and above the diagonal
+    /// at offset `k` as new `Tensor<usize, 2>` of `[row, col]` pairs, in row-major order.
+    ///
+    /// Returns `None` if there are no such values, since tensors can't be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let indices = Tensor::triu_indices(3, 3, 1).unwrap();
+    ///
+    /// assert_eq!(indices.as_slice(), &[0, 1, 0, 2, 1, 2]);
+    /// ```
+    pub fn triu_indices(rows: usize, cols: usize, k: isize) -> Option<Self> {
+        band_indices(rows, cols, k.saturating_neg(), isize::MAX)
+    }
+}
+
+/// Returns the indices of the values of a `rows x cols` matrix in the band between the offsets
+/// `lower` and `upper`.
+fn band_indices(rows: usize, cols: usize, lower: isize, upper: isize) -> Option<Tensor<usize, 2>> {
+    let mut indices = Vec::new();
+    for row in 0..rows {
+        for col in 0..cols {
+            if in_band(row, col, lower, upper) {
+                indices.push(row);
+                indices.push(col);
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return None;
+    }
+
+    let count = indices.len() / 2;
+    let metadata = TensorMetadata::new([count, 2]);
+
+    Some(Tensor::make_with(metadata, |p| indices[p]))
+}
+
+#[cfg(test)]
+mod band_tests {
+    use super::*;
+
+    #[test]
+    fn test_tril_triu_partition() {
+        let tensor = Tensor::make_with(TensorMetadata::new([2, 3, 4]), |p| p as i32 + 1);
+
+        for k in -4..=4 {
+            let lower = tensor.tril(k);
+            let upper = tensor.triu(k + 1);
+
+            assert_eq!(&lower + &upper, tensor);
+            assert!(
+                lower
+                    .iter()
+                    .zip(upper.iter())
+                    .all(|(a, b)| *a == 0 || *b == 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_inplace_matches() {
+        let tensor = Tensor::make_with(TensorMetadata::new([3, 2, 2, 3]), |p| p as f64);
+
+        let mut lower = tensor.clone();
+        lower.tril_inplace(1);
+        let mut upper = tensor.clone();
+        upper.triu_inplace(-1);
+
+        assert_eq!(lower, tensor.tril(1));
+        assert_eq!(upper, tensor.triu(-1));
+        assert_eq!(tensor.band_part(Some(1), Some(1)), tensor.tril(1).triu(-1));
+        assert_eq!(tensor.band_part(None, None), tensor);
+    }
+
+    #[test]
+    fn test_indices_match_masks() {
+        let ones = Tensor::new_set([3, 4], 1);
+
+        let indices = Tensor::tril_indices(3, 4, 1).unwrap();
+        let pairs = indices.as_slice().chunks(2);
+
+        assert_eq!(pairs.len(), ones.tril(1).iter().sum::<usize>());
+        for pair in pairs {
+            assert!(pair[1] <= pair[0] + 1);
+        }
+
+        assert_eq!(Tensor::triu_indices(3, 4, 4), None);
+        assert_eq!(Tensor::triu_indices(3, 4, -5).unwrap().shape(), &[12, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid rank")]
+    fn test_rank_too_small() {
+        Tensor::from_slice([3], &[1, 2, 3]).tril(0);
+    }
+}
@@ -0,0 +1,250 @@
+use core::hint::unreachable_unchecked;
+use core::ops::BitAnd;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+
+/// Computes the bitwise AND of `n` values of `a` and `b` and writes result to `r`.
+#[inline(always)]
+unsafe fn bitand<T>(n: usize, a: *const T, b: *const T, r: *mut T)
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(a_i & b_i);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> BitAnd<Self> for &Tensor<T, R>
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise bitwise AND between `self` and `other` tensor and returns new
+    /// `Tensor<T, R>` as a result of the bitwise AND without consuming `self` or `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor1 = Tensor::new_set([2, 3], 0b1100);
+    /// let tensor2 = Tensor::new_set([2, 3], 0b1010);
+    ///
+    /// let result = &tensor1 & &tensor2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &0b1000);
+    /// assert_eq!(result.get(&[1, 2]), &0b1000);
+    /// ```
+    fn bitand(self, other: Self) -> Tensor<T, R> {
+        assert_same_shape(self, other);
+
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+
+        let a = self.data.as_ptr();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            bitand(len, a, b, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> BitAnd<&Tensor<T, R>> for &mut Tensor<T, R>
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise bitwise AND between `self` and another tensor.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor1 = Tensor::new_set([2, 3], 0b1100);
+    /// let tensor2 = Tensor::new_set([2, 3], 0b1010);
+    ///
+    /// &mut tensor1 & &tensor2;
+    ///
+    /// assert_eq!(tensor1.get(&[0, 0]), &0b1000);
+    /// assert_eq!(tensor1.get(&[1, 2]), &0b1000);
+    /// ```
+    fn bitand(self, other: &Tensor<T, R>) {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            bitand(len, a, b, a);
+        }
+    }
+}
+
+/// Computes the bitwise AND of `n` values of `a` and `v`, and writes result to `r`.
+#[inline(always)]
+unsafe fn bitand_value<T>(n: usize, a: *const T, v: T, r: *mut T)
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(a_i & v);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> BitAnd<T> for &Tensor<T, R>
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise bitwise AND between `self` and `value` and returns result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 3], 0b1100);
+    ///
+    /// let result = &tensor & 0b0110;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &0b0100);
+    /// assert_eq!(result.get(&[1, 2]), &0b0100);
+    /// ```
+    fn bitand(self, value: T) -> Tensor<T, R> {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            bitand_value(len, a, value, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> BitAnd<T> for &mut Tensor<T, R>
+where
+    T: Copy + BitAnd<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise bitwise AND between `self` and `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 3], 0b1100);
+    ///
+    /// &mut tensor & 0b0110;
+    ///
+    /// assert_eq!(tensor.get(&[0, 0]), &0b0100);
+    /// assert_eq!(tensor.get(&[1, 2]), &0b0100);
+    /// ```
+    fn bitand(self, value: T) {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+
+        unsafe {
+            bitand_value(len, a, value, a);
+        }
+    }
+}
+
+#[cfg(test)]
+mod bitand_tests {
+    use super::*;
+
+    #[test]
+    fn test_bitand_new() {
+        let tensor1 = Tensor::from_slice([2, 2], &[0b1100u8, 0b1111, 0b0000, 0b1010]);
+        let tensor2 = Tensor::from_slice([2, 2], &[0b1010, 0b0101, 0b1111, 0b1010]);
+
+        let result = &tensor1 & &tensor2;
+
+        assert_eq!(result.as_slice(), &[0b1000, 0b0101, 0b0000, 0b1010]);
+    }
+
+    #[test]
+    fn test_bitand_mutate() {
+        let mut tensor1 = Tensor::from_slice([2, 2], &[0b1100u8, 0b1111, 0b0000, 0b1010]);
+        let tensor2 = Tensor::from_slice([2, 2], &[0b1010, 0b0101, 0b1111, 0b1010]);
+
+        &mut tensor1 & &tensor2;
+
+        assert_eq!(tensor1.as_slice(), &[0b1000, 0b0101, 0b0000, 0b1010]);
+    }
+
+    #[test]
+    fn test_bitand_value() {
+        let tensor = Tensor::from_slice([2, 2], &[0b1100u8, 0b1111, 0b0000, 0b1010]);
+
+        let result = &tensor & 0b0011;
+
+        assert_eq!(result.as_slice(), &[0b0000, 0b0011, 0b0000, 0b0010]);
+    }
+
+    #[test]
+    fn test_bitand_value_mut() {
+        let mut tensor = Tensor::from_slice([2, 2], &[0b1100u8, 0b1111, 0b0000, 0b1010]);
+
+        &mut tensor & 0b0011;
+
+        assert_eq!(tensor.as_slice(), &[0b0000, 0b0011, 0b0000, 0b0010]);
+    }
+}
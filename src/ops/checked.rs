@@ -0,0 +1,333 @@
+use core::hint::unreachable_unchecked;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::{assert_non_zero_divisors, assert_same_shape};
+use crate::num::Integer;
+use crate::ops::ArithmeticError;
+
+/// Writes `n` values returned by `f` for each offset to `r`.
+/// Returns the offset of the first value for which `f` returns `None`.
+#[inline(always)]
+unsafe fn try_fill<T, F>(n: usize, r: *mut T, f: F) -> Result<(), usize>
+where
+    F: Fn(usize) -> Option<T>,
+{
+    let mut i = 0;
+    while i < n {
+        match f(i) {
+            Some(r_i) => unsafe { r.add(i).write(r_i) },
+            None => return Err(i),
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Generates the checked variants of an operation.
+macro_rules! checked_fns {
+    ($($name:ident, $value:ident, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Performs checked element-wise ", $what, " between `self` and `other`")]
+            #[doc = "and returns new `Tensor<T, R>` as a result."]
+            #[doc = ""]
+            #[doc = "Returns an error with the index of the first value that overflows, or the"]
+            #[doc = "first divisor that is `0`."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            pub fn $name(&self, other: &Self) -> Result<Self, ArithmeticError<R>> {
+                assert_same_shape(self, other);
+
+                let a = &self.data;
+                let b = &other.data;
+
+                self.try_make_with(
+                    |i| unsafe { (*a.reference(i)).$name(*b.reference(i)) },
+                    |i| unsafe { *b.reference(i) },
+                )
+            }
+
+            #[doc = concat!("Performs checked element-wise ", $what, " between `self` and `value`")]
+            #[doc = "and returns new `Tensor<T, R>` as a result."]
+            #[doc = ""]
+            #[doc = "Returns an error with the index of the first value that overflows, or the"]
+            #[doc = "first index if `value` is a divisor of `0`."]
+            pub fn $value(&self, value: T) -> Result<Self, ArithmeticError<R>> {
+                let a = &self.data;
+
+                self.try_make_with(
+                    |i| unsafe { (*a.reference(i)).$name(value) },
+                    |_| value,
+                )
+            }
+        )*
+    };
+}
+
+/// Generates the non-failing variants of an operation.
+macro_rules! infallible_fns {
+    ($($name:ident, $value:ident, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Performs ", $what, " element-wise between `self` and `other` and")]
+            #[doc = "returns new `Tensor<T, R>` as a result."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            pub fn $name(&self, other: &Self) -> Self {
+                assert_same_shape(self, other);
+
+                let a = &self.data;
+                let b = &other.data;
+
+                Tensor::make_with(self.metadata, |i| unsafe {
+                    (*a.reference(i)).$name(*b.reference(i))
+                })
+            }
+
+            #[doc = concat!("Performs ", $what, " element-wise between `self` and `value` and")]
+            #[doc = "returns new `Tensor<T, R>` as a result."]
+            pub fn $value(&self, value: T) -> Self {
+                let a = &self.data;
+
+                Tensor::make_with(self.metadata, |i| unsafe { (*a.reference(i)).$name(value) })
+            }
+        )*
+    };
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Integer,
+{
+    /// Creates new tensor with the values returned by `f` for each offset.
+    ///
+    /// If `f` fails, the memory is released and the error is determined by the divisor
+    /// returned by `divisor` for the offset.
+    fn try_make_with<F, D>(&self, f: F, divisor: D) -> Result<Self, ArithmeticError<R>>
+    where
+        F: Fn(usize) -> Option<T>,
+        D: Fn(usize) -> T,
+    {
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            match try_fill(len, output.as_ptr_mut(), f) {
+                Ok(_) => Ok(Tensor {
+                    metadata: self.metadata,
+                    data: output,
+                }),
+                Err(offset) => {
+                    // Cleanup, integers don't need drop.
+                    output.release(layout);
+
+                    let index = self.metadata.index_of(offset);
+
+                    if divisor(offset) == T::ZERO {
+                        Err(ArithmeticError::DivisionByZero { index })
+                    } else {
+                        Err(ArithmeticError::Overflow { index })
+                    }
+                }
+            }
+        }
+    }
+
+    checked_fns! {
+        checked_add, checked_add_value, "addition";
+        checked_sub, checked_sub_value, "subtraction";
+        checked_mul, checked_mul_value, "multiplication";
+        checked_div, checked_div_value, "division";
+    }
+
+    infallible_fns! {
+        wrapping_add, wrapping_add_value, "wrapping (modular) addition";
+        wrapping_sub, wrapping_sub_value, "wrapping (modular) subtraction";
+        wrapping_mul, wrapping_mul_value, "wrapping (modular) multiplication";
+        saturating_add, saturating_add_value, "saturating addition";
+        saturating_sub, saturating_sub_value, "saturating subtraction";
+        saturating_mul, saturating_mul_value, "saturating multiplication";
+    }
+
+    /// Performs wrapping (modular) division element-wise between `self` and `other` and returns
+    /// new `Tensor<T, R>` as a result.
+    ///
+    /// The only case where wrapping occurs is `MIN / -1` of signed types, which results in `MIN`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match, or if any
+    /// value of `other` is `0`.
+    pub fn wrapping_div(&self, other: &Self) -> Self {
+        assert_same_shape(self, other);
+        assert_non_zero_divisors(other);
+
+        let a = &self.data;
+        let b = &other.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).wrapping_div(*b.reference(i))
+        })
+    }
+
+    /// Performs wrapping (modular) division element-wise between `self` and `value` and returns
+    /// new `Tensor<T, R>` as a result.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0`.
+    pub fn wrapping_div_value(&self, value: T) -> Self {
+        assert!(value != T::ZERO, "Division by zero");
+
+        let a = &self.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).wrapping_div(value)
+        })
+    }
+
+    /// Performs saturating division element-wise between `self` and `other` and returns new
+    /// `Tensor<T, R>` as a result.
+    ///
+    /// The only case where saturation occurs is `MIN / -1` of signed types, which results in
+    /// `MAX`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match, or if any
+    /// value of `other` is `0`.
+    pub fn saturating_div(&self, other: &Self) -> Self {
+        assert_same_shape(self, other);
+        assert_non_zero_divisors(other);
+
+        let a = &self.data;
+        let b = &other.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).saturating_div(*b.reference(i))
+        })
+    }
+
+    /// Performs saturating division element-wise between `self` and `value` and returns new
+    /// `Tensor<T, R>` as a result.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0`.
+    pub fn saturating_div_value(&self, value: T) -> Self {
+        assert!(value != T::ZERO, "Division by zero");
+
+        let a = &self.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).saturating_div(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod checked_tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_add() {
+        let a = Tensor::from_slice([2, 2], &[1u8, 2, 3, 4]);
+        let b = Tensor::from_slice([2, 2], &[10u8, 20, 30, 40]);
+
+        let result = a.checked_add(&b).unwrap();
+
+        assert_eq!(result.as_slice(), &[11, 22, 33, 44]);
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let a = Tensor::from_slice([2, 2], &[1u8, 2, 250, 255]);
+        let b = Tensor::new_set([2, 2], 10u8);
+
+        let result = a.checked_add(&b);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ArithmeticError::Overflow { index: [1, 0] }
+        );
+    }
+
+    #[test]
+    fn test_checked_sub_value_overflow() {
+        let a = Tensor::from_slice([3], &[5u32, 1, 7]);
+
+        let result = a.checked_sub_value(2);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ArithmeticError::Overflow { index: [1] }
+        );
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let a = Tensor::from_slice([2, 2], &[i32::MIN, 8, 9, 10]);
+        let b = Tensor::from_slice([2, 2], &[1, 2, 3, 5]);
+
+        assert_eq!(a.checked_div(&b).unwrap().as_slice(), &[i32::MIN, 4, 3, 2]);
+
+        let b = Tensor::from_slice([2, 2], &[1, 2, 0, -1]);
+
+        assert_eq!(
+            a.checked_div(&b).unwrap_err(),
+            ArithmeticError::DivisionByZero { index: [1, 0] }
+        );
+
+        let b = Tensor::from_slice([2, 2], &[-1, 2, 0, 1]);
+
+        assert_eq!(
+            a.checked_div(&b).unwrap_err(),
+            ArithmeticError::Overflow { index: [0, 0] }
+        );
+    }
+
+    #[test]
+    fn test_checked_div_value_by_zero() {
+        let a = Tensor::new_set([2, 3], 4i64);
+
+        assert_eq!(
+            a.checked_div_value(0).unwrap_err(),
+            ArithmeticError::DivisionByZero { index: [0, 0] }
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let a = Tensor::from_slice([3], &[i8::MAX, i8::MIN, 0]);
+
+        assert_eq!(a.wrapping_add_value(1).as_slice(), &[i8::MIN, -127, 1]);
+        assert_eq!(a.wrapping_div_value(-1).as_slice(), &[-127, i8::MIN, 0]);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let a = Tensor::from_slice([3], &[250u8, 5, 0]);
+        let b = Tensor::from_slice([3], &[10u8, 10, 1]);
+
+        assert_eq!(a.saturating_add(&b).as_slice(), &[255, 15, 1]);
+        assert_eq!(a.saturating_sub(&b).as_slice(), &[240, 0, 0]);
+        assert_eq!(a.saturating_mul_value(2).as_slice(), &[255, 10, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_saturating_div_by_zero() {
+        let a = Tensor::from_slice([2], &[1, 2]);
+        let b = Tensor::from_slice([2], &[1, 0]);
+
+        let _ = a.saturating_div(&b);
+    }
+}
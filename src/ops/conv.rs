@@ -0,0 +1,613 @@
+use crate::Tensor;
+use crate::num::Float;
+
+use super::gemm::{MatrixRef, gemm};
+
+/// Values read outside of the input of a convolution or pooling.
+/// The following modes are defined:
+/// - `Zero`: Zeros.
+/// - `Reflect`: The input reflected at its edges, without repeating the edge values.
+/// - `Replicate`: The edge values of the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PaddingMode {
+    #[default]
+    Zero,
+    Reflect,
+    Replicate,
+}
+
+/// Parameters of a convolution over `D` spatial dimensions.
+///
+/// The default is a stride and dilation of `1`, no padding and a single group.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConvOptions<const D: usize> {
+    /// Step between the input positions of consecutive outputs, in each dimension.
+    pub stride: [usize; D],
+    /// Number of padded positions on both sides of the input, in each dimension.
+    pub padding: [usize; D],
+    /// Values of the padded positions.
+    pub padding_mode: PaddingMode,
+    /// Step between the input positions read by consecutive kernel values, in each dimension.
+    pub dilation: [usize; D],
+    /// Number of groups the input and output channels are split into, each group of outputs
+    /// reading only the corresponding group of inputs.
+    pub groups: usize,
+}
+
+impl<const D: usize> Default for ConvOptions<D> {
+    fn default() -> Self {
+        Self {
+            stride: [1; D],
+            padding: [0; D],
+            padding_mode: PaddingMode::Zero,
+            dilation: [1; D],
+            groups: 1,
+        }
+    }
+}
+
+/// Returns the position read in an input of `size` values for the padded position `position`,
+/// or `None` if it is a zero.
+#[inline(always)]
+pub(super) const fn padded_position(
+    position: usize,
+    padding: usize,
+    size: usize,
+    mode: PaddingMode,
+) -> Option<usize> {
+    if position >= padding && position - padding < size {
+        return Some(position - padding);
+    }
+
+    match mode {
+        PaddingMode::Zero => None,
+        PaddingMode::Replicate if position < padding => Some(0),
+        PaddingMode::Replicate => Some(size - 1),
+        PaddingMode::Reflect if position < padding => Some(padding - position),
+        PaddingMode::Reflect => Some(2 * (size - 1) + padding - position),
+    }
+}
+
+/// Returns the number of outputs of a window of `span` values moving by `stride` over `size`
+/// values padded with `padding` values on both sides.
+///
+/// # Panics
+/// This function will panic if the stride is `0`, if the reflected padding is not less than the
+/// size, or if the window is larger than the padded input.
+pub(super) const fn output_size(
+    size: usize,
+    span: usize,
+    stride: usize,
+    padding: usize,
+    mode: PaddingMode,
+) -> usize {
+    assert!(stride > 0, "Stride must be greater than `0`");
+    assert!(
+        !matches!(mode, PaddingMode::Reflect) || padding < size,
+        "Reflect padding must be less than the input size"
+    );
+
+    let padded = size + 2 * padding;
+    assert!(span <= padded, "Window larger than padded input");
+
+    (padded - span) / stride + 1
+}
+
+/// Returns the spatial dimensions of the output of a window of dimensions `window` moving over
+/// the spatial dimensions `input` with `options`.
+///
+/// # Panics
+/// This function will panic if a window size, stride or dilation is `0`, if a reflected padding
+/// is not less than the input size, or if the dilated window is larger than the padded input.
+pub(super) fn window_output_dims<const D: usize>(
+    input: &[usize],
+    window: &[usize],
+    options: &ConvOptions<D>,
+) -> [usize; D] {
+    let mut output = [0; D];
+    for d in 0..D {
+        let dilation = options.dilation[d];
+        assert!(window[d] > 0, "Window size must be greater than `0`");
+        assert!(dilation > 0, "Dilation must be greater than `0`");
+
+        let span = dilation * (window[d] - 1) + 1;
+        output[d] = output_size(
+            input[d],
+            span,
+            options.stride[d],
+            options.padding[d],
+            options.padding_mode,
+        );
+    }
+    output
+}
+
+/// Returns the offset within an input channel of spatial dimensions `input` read by each
+/// position of the window for each output position, both in row-major order, or `None` for a
+/// padded zero.
+pub(super) fn window_sources<const D: usize>(
+    input: &[usize],
+    window: &[usize],
+    output: &[usize; D],
+    options: &ConvOptions<D>,
+) -> Vec<Option<usize>> {
+    let window_size: usize = window.iter().product();
+    let output_size: usize = output.iter().product();
+
+    let mut sources = Vec::with_capacity(window_size * output_size);
+    for wp in 0..window_size {
+        for op in 0..output_size {
+            let (mut w, mut o) = (wp, op);
+            let mut offset = Some(0);
+            let mut stride = 1;
+
+            for d in (0..D).rev() {
+                let (w_d, o_d) = (w % window[d], o % output[d]);
+                w /= window[d];
+                o /= output[d];
+
+                let position = o_d * options.stride[d] + w_d * options.dilation[d];
+                let source =
+                    padded_position(position, options.padding[d], input[d], options.padding_mode);
+
+                offset = match (offset, source) {
+                    (Some(offset), Some(source)) => Some(offset + source * stride),
+                    _ => None,
+                };
+                stride *= input[d];
+            }
+            sources.push(offset);
+        }
+    }
+    sources
+}
+
+/// Copies the values of `channel` read by each window position into the rows of `columns`, one
+/// column per output position, with zeros for padded positions.
+pub(super) fn gather_columns<T>(channel: &[T], sources: &[Option<usize>], columns: &mut [T])
+where
+    T: Float,
+{
+    for (value, source) in columns.iter_mut().zip(sources) {
+        *value = match source {
+            Some(i) => channel[*i],
+            None => T::ZERO,
+        };
+    }
+}
+
+/// Convolves the `N x C x ..` input with the `O x C / groups x ..` kernel over the `D` spatial
+/// dimensions, flipping the kernel if `flip`, with the im2col strategy: the input values read by
+/// each kernel value for all output positions are copied into the rows of a matrix, which is
+/// multiplied by the kernel matrix of each group.
+fn convolve<T, const D: usize, const R: usize>(
+    input: &Tensor<T, R>,
+    kernel: &Tensor<T, R>,
+    options: ConvOptions<D>,
+    flip: bool,
+) -> Tensor<T, R>
+where
+    T: Float,
+{
+    let dims = input.metadata.dims();
+    let kernel_dims = kernel.metadata.dims();
+    let (batch, channels) = (dims[0], dims[1]);
+    let (filters, group_channels) = (kernel_dims[0], kernel_dims[1]);
+    let groups = options.groups;
+
+    assert!(
+        groups > 0 && channels % groups == 0 && filters % groups == 0,
+        "Invalid number of groups"
+    );
+    assert!(
+        group_channels * groups == channels,
+        "Kernel channels must match the input channels per group"
+    );
+
+    let spatial = window_output_dims(&dims[2..], &kernel_dims[2..], &options);
+
+    let mut output_dims = [0; R];
+    output_dims[0] = batch;
+    output_dims[1] = filters;
+    output_dims[2..].copy_from_slice(&spatial);
+
+    let input_size: usize = dims[2..].iter().product();
+    let kernel_size: usize = kernel_dims[2..].iter().product();
+    let output_size: usize = spatial.iter().product();
+
+    let sources = window_sources(&dims[2..], &kernel_dims[2..], &spatial, &options);
+
+    // Flipping every spatial axis reverses the row-major order of the kernel positions.
+    let weights = kernel.as_slice();
+    let weights: Vec<T> = if flip {
+        (0..weights.len())
+            .map(|p| {
+                let (row, kp) = (p / kernel_size, p % kernel_size);
+                weights[row * kernel_size + kernel_size - 1 - kp]
+            })
+            .collect()
+    } else {
+        weights.to_vec()
+    };
+
+    let x = input.as_slice();
+    let rows = group_channels * kernel_size;
+    let group_filters = filters / groups;
+
+    let mut columns = vec![T::ZERO; rows * output_size];
+    let mut output = vec![T::ZERO; batch * filters * output_size];
+
+    for n in 0..batch {
+        for g in 0..groups {
+            for c in 0..group_channels {
+                let channel = (n * channels + g * group_channels + c) * input_size;
+                let rows = c * kernel_size * output_size;
+
+                gather_columns(
+                    &x[channel..channel + input_size],
+                    &sources,
+                    &mut columns[rows..rows + kernel_size * output_size],
+                );
+            }
+
+            let filter = g * group_filters;
+            unsafe {
+                gemm(
+                    group_filters,
+                    output_size,
+                    rows,
+                    MatrixRef::row_major(weights.as_ptr().add(filter * rows), rows),
+                    MatrixRef::row_major(columns.as_ptr(), output_size),
+                    output
+                        .as_mut_ptr()
+                        .add((n * filters + filter) * output_size),
+                    false,
+                );
+            }
+        }
+    }
+
+    Tensor::from_boxed_slice(output_dims, output.into_boxed_slice())
+}
+
+impl<T> Tensor<T, 3>
+where
+    T: Float,
+{
+    /// Returns the convolution of the `N x C x L` input with the `O x C / groups x K` kernel as
+    /// new `N x O x L'` tensor, flipping the kernel as in signal processing.
+    ///
+    /// # Panics
+    /// This method will panic if `options.groups` doesn't divide `C` and `O`, if the kernel's
+    /// channels are not `C / groups`, if a stride or dilation is `0`, if a reflected padding is
+    /// not less than the input size, or if the dilated kernel is larger than the padded input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 4], &[1.0, 2.0, 3.0, 4.0]);
+    /// let kernel = Tensor::from_slice([1, 1, 2], &[1.0, -1.0]);
+    ///
+    /// let result = signal.conv1d(&kernel, ConvOptions::default());
+    ///
+    /// assert_eq!(result.as_slice(), &[1.0, 1.0, 1.0]);
+    /// ```
+    pub fn conv1d(&self, kernel: &Self, options: ConvOptions<1>) -> Self {
+        convolve(self, kernel, options, true)
+    }
+
+    /// Returns the cross-correlation of the `N x C x L` input with the `O x C / groups x K`
+    /// kernel as new `N x O x L'` tensor, the convolution of deep learning frameworks.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `conv1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, PaddingMode, Tensor};
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 4], &[1.0, 2.0, 3.0, 4.0]);
+    /// let kernel = Tensor::from_slice([1, 1, 2], &[1.0, -1.0]);
+    ///
+    /// let options = ConvOptions {
+    ///     padding: [1],
+    ///     padding_mode: PaddingMode::Replicate,
+    ///     ..ConvOptions::default()
+    /// };
+    /// let result = signal.correlate1d(&kernel, options);
+    ///
+    /// assert_eq!(result.as_slice(), &[0.0, -1.0, -1.0, -1.0, 0.0]);
+    /// ```
+    pub fn correlate1d(&self, kernel: &Self, options: ConvOptions<1>) -> Self {
+        convolve(self, kernel, options, false)
+    }
+}
+
+impl<T> Tensor<T, 4>
+where
+    T: Float,
+{
+    /// Returns the convolution of the `N x C x H x W` input with the `O x C / groups x KH x KW`
+    /// kernel as new `N x O x H' x W'` tensor, flipping the kernel as in signal processing.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `conv1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let image = Tensor::from_slice([1, 1, 3, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// let kernel = Tensor::from_slice([1, 1, 2, 2], &[1.0, 0.0, 0.0, 0.0]);
+    ///
+    /// let result = image.conv2d(&kernel, ConvOptions::default());
+    ///
+    /// // The flipped kernel picks the bottom-right value of each window.
+    /// assert_eq!(result.as_slice(), &[5.0, 6.0, 8.0, 9.0]);
+    /// ```
+    pub fn conv2d(&self, kernel: &Self, options: ConvOptions<2>) -> Self {
+        convolve(self, kernel, options, true)
+    }
+
+    /// Returns the cross-correlation of the `N x C x H x W` input with the
+    /// `O x C / groups x KH x KW` kernel as new `N x O x H' x W'` tensor.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `conv1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let image = Tensor::from_slice([1, 1, 3, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// let kernel = Tensor::from_slice([1, 1, 2, 2], &[1.0, 0.0, 0.0, 0.0]);
+    ///
+    /// let options = ConvOptions {
+    ///     stride: [2, 2],
+    ///     padding: [1, 1],
+    ///     ..ConvOptions::default()
+    /// };
+    /// let result = image.correlate2d(&kernel, options);
+    ///
+    /// assert_eq!(result.as_slice(), &[0.0, 0.0, 0.0, 5.0]);
+    /// ```
+    pub fn correlate2d(&self, kernel: &Self, options: ConvOptions<2>) -> Self {
+        convolve(self, kernel, options, false)
+    }
+}
+
+impl<T> Tensor<T, 5>
+where
+    T: Float,
+{
+    /// Returns the convolution of the `N x C x D x H x W` input with the
+    /// `O x C / groups x KD x KH x KW` kernel as new `N x O x D' x H' x W'` tensor, flipping the
+    /// kernel as in signal processing.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `conv1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let volume = Tensor::new_set([1, 1, 2, 2, 2], 1.0);
+    /// let kernel = Tensor::new_set([2, 1, 2, 2, 2], 0.5);
+    ///
+    /// let result = volume.conv3d(&kernel, ConvOptions::default());
+    ///
+    /// assert_eq!(result.shape(), &[1, 2, 1, 1, 1]);
+    /// assert_eq!(result.as_slice(), &[4.0, 4.0]);
+    /// ```
+    pub fn conv3d(&self, kernel: &Self, options: ConvOptions<3>) -> Self {
+        convolve(self, kernel, options, true)
+    }
+
+    /// Returns the cross-correlation of the `N x C x D x H x W` input with the
+    /// `O x C / groups x KD x KH x KW` kernel as new `N x O x D' x H' x W'` tensor.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `conv1d`.
+    pub fn correlate3d(&self, kernel: &Self, options: ConvOptions<3>) -> Self {
+        convolve(self, kernel, options, false)
+    }
+}
+
+#[cfg(test)]
+mod conv_tests {
+    use super::*;
+    use crate::metadata::TensorMetadata;
+
+    /// Direct cross-correlation of a `N x C x H x W` input, reading padded values one by one.
+    fn direct2d(input: &Tensor<f64, 4>, kernel: &Tensor<f64, 4>, o: ConvOptions<2>) -> Vec<f64> {
+        let [batch, _, h, w] = input.metadata.dims();
+        let [filters, group_channels, kh, kw] = kernel.metadata.dims();
+        let group_filters = filters / o.groups;
+
+        let out_h = (h + 2 * o.padding[0] - o.dilation[0] * (kh - 1) - 1) / o.stride[0] + 1;
+        let out_w = (w + 2 * o.padding[1] - o.dilation[1] * (kw - 1) - 1) / o.stride[1] + 1;
+
+        let mut output = Vec::new();
+        for n in 0..batch {
+            for f in 0..filters {
+                let g = f / group_filters;
+                for y in 0..out_h {
+                    for x in 0..out_w {
+                        let mut sum = 0.0;
+                        for c in 0..group_channels {
+                            for i in 0..kh {
+                                for j in 0..kw {
+                                    let py = y * o.stride[0] + i * o.dilation[0];
+                                    let px = x * o.stride[1] + j * o.dilation[1];
+                                    let sy = padded_position(py, o.padding[0], h, o.padding_mode);
+                                    let sx = padded_position(px, o.padding[1], w, o.padding_mode);
+
+                                    if let (Some(sy), Some(sx)) = (sy, sx) {
+                                        let channel = g * group_channels + c;
+                                        sum += input.get(&[n, channel, sy, sx])
+                                            * kernel.get(&[f, c, i, j]);
+                                    }
+                                }
+                            }
+                        }
+                        output.push(sum);
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-10, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_correlate2d_matches_direct() {
+        let input = Tensor::make_with(TensorMetadata::new([2, 4, 5, 6]), |p| {
+            ((p * 7919) % 23) as f64 - 11.0
+        });
+        let kernel = Tensor::make_with(TensorMetadata::new([6, 2, 3, 2]), |p| {
+            ((p * 104729) % 17) as f64 / 4.0 - 2.0
+        });
+
+        for padding_mode in [
+            PaddingMode::Zero,
+            PaddingMode::Reflect,
+            PaddingMode::Replicate,
+        ] {
+            let options = ConvOptions {
+                stride: [2, 1],
+                padding: [2, 1],
+                padding_mode,
+                dilation: [1, 2],
+                groups: 2,
+            };
+
+            let result = input.correlate2d(&kernel, options);
+
+            assert_eq!(result.shape(), &[2, 6, 4, 6]);
+            assert_close(result.as_slice(), &direct2d(&input, &kernel, options));
+        }
+    }
+
+    #[test]
+    fn test_conv_flips_kernel() {
+        let input = Tensor::make_with(TensorMetadata::new([1, 2, 4, 4]), |p| p as f64);
+        let kernel = Tensor::make_with(TensorMetadata::new([3, 2, 2, 3]), |p| p as f64 - 5.0);
+
+        // Kernel with both spatial axes reversed.
+        let flipped = Tensor::make_with(TensorMetadata::new([3, 2, 2, 3]), |p| {
+            let [f, c, i, j] = kernel.metadata.index_of(p);
+            *kernel.get(&[f, c, 1 - i, 2 - j])
+        });
+
+        let options = ConvOptions {
+            padding: [1, 1],
+            ..ConvOptions::default()
+        };
+
+        assert_eq!(
+            input.conv2d(&kernel, options),
+            input.correlate2d(&flipped, options)
+        );
+    }
+
+    #[test]
+    fn test_conv1d_and_conv3d_match_conv2d() {
+        let signal = Tensor::make_with(TensorMetadata::new([2, 3, 7]), |p| (p % 5) as f64);
+        let kernel = Tensor::make_with(TensorMetadata::new([2, 3, 3]), |p| p as f64 * 0.5);
+
+        let options = ConvOptions {
+            stride: [2],
+            padding: [2],
+            padding_mode: PaddingMode::Reflect,
+            dilation: [2],
+            groups: 1,
+        };
+        let options2d = ConvOptions {
+            stride: [1, 2],
+            padding: [0, 2],
+            padding_mode: PaddingMode::Reflect,
+            dilation: [1, 2],
+            groups: 1,
+        };
+
+        let result = signal.conv1d(&kernel, options);
+        let image = signal.clone().change_rank([2, 3, 1, 7]);
+        let image_kernel = kernel.clone().change_rank([2, 3, 1, 3]);
+        let expected = image.conv2d(&image_kernel, options2d);
+
+        assert_eq!(result.shape(), &[2, 2, 4]);
+        assert_eq!(result.as_slice(), expected.as_slice());
+
+        let volume = signal.clone().change_rank([2, 3, 1, 1, 7]);
+        let volume_kernel = kernel.clone().change_rank([2, 3, 1, 1, 3]);
+        let options3d = ConvOptions {
+            stride: [1, 1, 2],
+            padding: [0, 0, 2],
+            padding_mode: PaddingMode::Reflect,
+            dilation: [1, 1, 2],
+            groups: 1,
+        };
+
+        let result3d = volume.conv3d(&volume_kernel, options3d);
+
+        assert_eq!(result3d.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_padding_positions() {
+        // Input [a, b, c] padded with 2 on both sides.
+        let read = |mode| {
+            (0..7)
+                .map(|p| padded_position(p, 2, 3, mode))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(read(PaddingMode::Reflect), [2, 1, 0, 1, 2, 1, 0].map(Some));
+        assert_eq!(
+            read(PaddingMode::Replicate),
+            [0, 0, 0, 1, 2, 2, 2].map(Some)
+        );
+        assert_eq!(
+            read(PaddingMode::Zero),
+            [None, None, Some(0), Some(1), Some(2), None, None]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of groups")]
+    fn test_invalid_groups() {
+        let input = Tensor::new_set([1, 3, 4], 1.0f32);
+        let kernel = Tensor::new_set([2, 1, 2], 1.0f32);
+
+        let options = ConvOptions {
+            groups: 3,
+            ..ConvOptions::default()
+        };
+        input.conv1d(&kernel, options);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window larger than padded input")]
+    fn test_kernel_too_large() {
+        let input = Tensor::new_set([1, 1, 2], 1.0);
+        let kernel = Tensor::new_set([1, 1, 2], 1.0);
+
+        let options = ConvOptions {
+            dilation: [2],
+            ..ConvOptions::default()
+        };
+        input.correlate1d(&kernel, options);
+    }
+}
@@ -0,0 +1,234 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::Tensor;
+use crate::assertions::assert_valid_axis;
+use crate::metadata::TensorMetadata;
+
+use super::minmax::{maximum, minimum};
+
+/// Replaces each of `len` values along the lanes of `a`, with `inner` distance between the values
+/// of a lane, by `f` applied to the accumulated previous value and itself.
+#[inline(always)]
+unsafe fn scan<T, F>(outer: usize, len: usize, inner: usize, a: *mut T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut o = 0;
+    while o < outer {
+        let mut i = 0;
+        while i < inner {
+            unsafe {
+                let lane = a.add(o * len * inner + i);
+                let mut acc = *lane;
+                let mut k = 1;
+                while k < len {
+                    let p = lane.add(k * inner);
+                    acc = f(acc, *p);
+                    p.write(acc);
+                    k += 1;
+                }
+            }
+            i += 1;
+        }
+        o += 1;
+    }
+}
+
+/// Generates the new and in-place variants of a cumulative operation.
+macro_rules! scan_fns {
+    ($($name:ident, $inplace:ident, $f:expr, $what:literal, $bound:path;)*) => {
+        $(
+            #[doc = concat!("Computes the cumulative ", $what, " of the elements along `axis` and")]
+            #[doc = "returns the result as new `Tensor<T, R>` of the same shape."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if `axis` is not less than `R`."]
+            pub fn $name(&self, axis: usize) -> Self
+            where
+                T: $bound,
+            {
+                let mut output = self.clone();
+                output.scan_axis_inplace(axis, $f);
+                output
+            }
+
+            #[doc = concat!("Computes the cumulative ", $what, " of the elements along `axis`")]
+            #[doc = "in-place."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if `axis` is not less than `R`."]
+            pub fn $inplace(&mut self, axis: usize)
+            where
+                T: $bound,
+            {
+                self.scan_axis_inplace(axis, $f)
+            }
+        )*
+    };
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy,
+{
+    /// Accumulates in-place the elements of each lane along `axis` with `f`.
+    fn scan_axis_inplace<F>(&mut self, axis: usize, f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_valid_axis::<R>(axis);
+
+        let (outer, len, inner) = self.metadata.split_at_axis(axis);
+        let a = self.data.as_ptr_mut();
+
+        unsafe { scan(outer, len, inner, a, f) }
+    }
+
+    scan_fns! {
+        cumsum, cumsum_inplace, |acc, x| acc + x, "sum", Add<Output = T>;
+        cumprod, cumprod_inplace, |acc, x| acc * x, "product", Mul<Output = T>;
+        cummax, cummax_inplace, maximum, "maximum (NaN is propagated)", PartialOrd;
+        cummin, cummin_inplace, minimum, "minimum (NaN is propagated)", PartialOrd;
+    }
+
+    /// Computes the `n`-th discrete difference along `axis` and returns the result as new
+    /// `Tensor<T, R>`, whose dimension along `axis` is `n` less than the one of `self`.
+    ///
+    /// The first difference is given by `out[k] = a[k + 1] - a[k]`, higher differences are
+    /// computed by applying it repeatedly. `n` of `0` returns a copy of `self`.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `n` is not less than the
+    /// dimension of `self` along `axis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 4], &[1, 2, 4, 7, 0, 1, 0, 1]);
+    ///
+    /// let first = tensor.diff(1, 1);
+    /// let second = tensor.diff(2, 1);
+    ///
+    /// assert_eq!(first.shape(), &[2, 3]);
+    /// assert_eq!(first.as_slice(), &[1, 2, 3, 1, -1, 1]);
+    /// assert_eq!(second.as_slice(), &[1, 1, -2, 2]);
+    /// ```
+    pub fn diff(&self, n: usize, axis: usize) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        assert_valid_axis::<R>(axis);
+        assert!(
+            n < self.metadata.dims()[axis],
+            "Difference order out of bounds"
+        );
+
+        let mut output = self.clone();
+        let mut k = 0;
+        while k < n {
+            output = output.diff_once(axis);
+            k += 1;
+        }
+        output
+    }
+
+    /// Computes the first discrete difference along `axis`, the dimension along `axis` must be
+    /// greater than `1`.
+    fn diff_once(&self, axis: usize) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+
+        let mut dims = self.metadata.dims();
+        dims[axis] -= 1;
+
+        let a = &self.data;
+
+        // Each lane of the result is one shorter, so the offset `j` of the result maps to the
+        // offset `j + (j / ((len - 1) * inner)) * inner` of `self`.
+        Tensor::make_with(TensorMetadata::new(dims), |j| {
+            let p = j + (j / ((len - 1) * inner)) * inner;
+            unsafe { *a.reference(p + inner) - *a.reference(p) }
+        })
+    }
+}
+
+#[cfg(test)]
+mod cumulative_tests {
+    use super::*;
+
+    #[test]
+    fn test_cumsum() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(tensor.cumsum(0).as_slice(), &[1, 2, 3, 5, 7, 9]);
+        assert_eq!(tensor.cumsum(1).as_slice(), &[1, 3, 6, 4, 9, 15]);
+    }
+
+    #[test]
+    fn test_cumprod_inplace() {
+        let mut tensor = Tensor::from_slice([2, 2, 2], &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        tensor.cumprod_inplace(1);
+
+        assert_eq!(tensor.as_slice(), &[1, 2, 3, 8, 5, 6, 35, 48]);
+    }
+
+    #[test]
+    fn test_cummax_cummin() {
+        let tensor = Tensor::from_slice([5], &[3.0, 1.0, 4.0, f64::NAN, 5.0]);
+
+        let max = tensor.cummax(0);
+        let min = tensor.cummin(0);
+
+        assert_eq!(max.as_slice()[..3], [3.0, 3.0, 4.0]);
+        assert_eq!(min.as_slice()[..3], [3.0, 1.0, 1.0]);
+        assert!(max.as_slice()[3..].iter().all(|x| x.is_nan()));
+        assert!(min.as_slice()[3..].iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_diff() {
+        let tensor = Tensor::from_slice([3, 2], &[1, 10, 4, 20, 9, 40]);
+
+        let first = tensor.diff(1, 0);
+        assert_eq!(first.shape(), &[2, 2]);
+        assert_eq!(first.as_slice(), &[3, 10, 5, 20]);
+
+        let second = tensor.diff(2, 0);
+        assert_eq!(second.shape(), &[1, 2]);
+        assert_eq!(second.as_slice(), &[2, 10]);
+
+        assert_eq!(tensor.diff(0, 1).as_slice(), tensor.as_slice());
+    }
+
+    #[test]
+    fn test_diff_middle_axis() {
+        let tensor = Tensor::from_slice([2, 3, 2], &[0, 0, 1, 2, 3, 6, 0, 0, 2, 1, 6, 2]);
+
+        let result = tensor.diff(1, 1);
+
+        assert_eq!(result.shape(), &[2, 2, 2]);
+        assert_eq!(result.as_slice(), &[1, 2, 2, 4, 2, 1, 4, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Difference order out of bounds")]
+    fn test_diff_order_out_of_bounds() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+
+        let _ = tensor.diff(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis out of bounds")]
+    fn test_cumsum_axis_out_of_bounds() {
+        let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+
+        let _ = tensor.cumsum(2);
+    }
+}
@@ -0,0 +1,181 @@
+use core::iter::StepBy;
+use core::ops::AddAssign;
+use core::slice::Iter;
+
+use crate::Tensor;
+use crate::metadata::TensorMetadata;
+
+/// A view of a diagonal of a matrix, borrowing the matrix's values.
+#[derive(Debug, Clone, Copy)]
+pub struct Diagonal<'a, T> {
+    values: &'a [T],
+    step: usize,
+    len: usize,
+}
+
+impl<'a, T> Diagonal<'a, T> {
+    /// Returns the number of values on the diagonal.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the diagonal has no values, which never happens for a valid offset.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the `index`-th value of the diagonal, or `None` if it is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index < self.len {
+            Some(&self.values[index * self.step])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the values of the diagonal.
+    pub fn iter(&self) -> StepBy<Iter<'a, T>> {
+        self.values.iter().step_by(self.step)
+    }
+
+    /// Copies the values of the diagonal into a new vector.
+    pub fn to_tensor(&self) -> Tensor<T, 1>
+    where
+        T: Copy,
+    {
+        Tensor::make_with(TensorMetadata::new([self.len]), |i| {
+            self.values[i * self.step]
+        })
+    }
+}
+
+impl<T> Tensor<T, 2> {
+    /// Returns a view of the diagonal at `offset` of the matrix, above the main diagonal if
+    /// `offset` is positive and below it if negative.
+    ///
+    /// # Panics
+    /// This method will panic if the diagonal at `offset` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(matrix.diagonal(0).iter().collect::<Vec<_>>(), [&1, &5]);
+    /// assert_eq!(matrix.diagonal(1).to_tensor().as_slice(), &[2, 6]);
+    /// assert_eq!(matrix.diagonal(-1).get(0), Some(&4));
+    /// ```
+    pub fn diagonal(&self, offset: isize) -> Diagonal<'_, T> {
+        let [rows, cols] = self.metadata.dims();
+        let shift = offset.unsigned_abs();
+
+        let (start, len) = if offset >= 0 {
+            (shift, rows.min(cols.saturating_sub(shift)))
+        } else {
+            (shift * cols, rows.saturating_sub(shift).min(cols))
+        };
+
+        assert!(len > 0, "Diagonal offset out of bounds");
+
+        // The view ends at the last value of the diagonal.
+        let step = cols + 1;
+        let values = &self.as_slice()[start..=start + (len - 1) * step];
+
+        Diagonal { values, step, len }
+    }
+
+    /// Returns the sum of the values on the main diagonal of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([3, 2], &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(matrix.trace(), 5);
+    /// ```
+    pub fn trace(&self) -> T
+    where
+        T: Copy + Default + AddAssign,
+    {
+        let mut sum = T::default();
+        for &value in self.diagonal(0).iter() {
+            sum += value;
+        }
+        sum
+    }
+
+    /// Creates a square matrix with the values of `vector` on its main diagonal and
+    /// `T::default()` elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let vector = Tensor::from_slice([2], &[3, 4]);
+    ///
+    /// let matrix = Tensor::diag(&vector);
+    ///
+    /// assert_eq!(matrix.as_slice(), &[3, 0, 0, 4]);
+    /// ```
+    pub fn diag(vector: &Tensor<T, 1>) -> Self
+    where
+        T: Copy + Default,
+    {
+        let n = vector.metadata.size();
+        let values = vector.as_slice();
+
+        Tensor::make_with(TensorMetadata::new([n, n]), |p| {
+            if p % (n + 1) == 0 {
+                values[p / (n + 1)]
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod diagonal_tests {
+    use super::*;
+
+    #[test]
+    fn test_diagonal_offsets() {
+        let matrix = Tensor::make_with(TensorMetadata::new([3, 4]), |p| p);
+
+        let collect = |offset| matrix.diagonal(offset).iter().copied().collect::<Vec<_>>();
+
+        assert_eq!(collect(0), [0, 5, 10]);
+        assert_eq!(collect(1), [1, 6, 11]);
+        assert_eq!(collect(3), [3]);
+        assert_eq!(collect(-1), [4, 9]);
+        assert_eq!(collect(-2), [8]);
+
+        let diagonal = matrix.diagonal(2);
+        assert_eq!(diagonal.len(), 2);
+        assert_eq!(diagonal.get(1), Some(&7));
+        assert_eq!(diagonal.get(2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Diagonal offset out of bounds")]
+    fn test_diagonal_out_of_bounds() {
+        Tensor::new_set([2, 2], 0).diagonal(-2);
+    }
+
+    #[test]
+    fn test_diag_round_trip() {
+        let vector = Tensor::from_slice([3], &[1.5, -2.0, 4.0]);
+
+        let matrix = Tensor::diag(&vector);
+
+        assert_eq!(matrix.diagonal(0).to_tensor(), vector);
+        assert_eq!(matrix.trace(), 3.5);
+        assert_eq!(matrix.iter().filter(|&&x| x == 0.0).count(), 6);
+    }
+}
@@ -0,0 +1,14 @@
+/// Error type for checked arithmetic operations.
+/// This enum is used to represent the different types of errors that can occur during checked
+/// arithmetic on tensors of rank `R`.
+/// The following errors are defined:
+/// - `Overflow`: The result at `index` is too large or too small to be represented by the type.
+/// - `DivisionByZero`: The divisor at `index` is `0`.
+///
+/// `index` is the multidimensional index of the first value in row-major order for which the
+/// operation failed.
+#[derive(Debug, PartialEq)]
+pub enum ArithmeticError<const R: usize> {
+    Overflow { index: [usize; R] },
+    DivisionByZero { index: [usize; R] },
+}
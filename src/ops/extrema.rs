@@ -0,0 +1,294 @@
+use core::cmp::Ordering;
+
+use crate::Tensor;
+use crate::assertions::{assert_reduced_rank, assert_valid_axis};
+use crate::metadata::TensorMetadata;
+use crate::num::TotalOrder;
+
+/// Returns the offset of the first of `n` values of `a`, with `step` distance between them, for
+/// which no later value compares as `ordering` to it.
+#[inline(always)]
+unsafe fn arg_extreme<T>(n: usize, step: usize, a: *const T, ordering: Ordering) -> usize
+where
+    T: TotalOrder,
+{
+    let mut best = 0;
+    let mut k = 1;
+    while k < n {
+        unsafe {
+            if (*a.add(k * step)).total_order(&*a.add(best * step)) == ordering {
+                best = k;
+            }
+        }
+        k += 1;
+    }
+    best
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: TotalOrder,
+{
+    /// Returns the index of the first greatest element in `TotalOrder`, so the index of the first
+    /// positive NaN if any for floats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 9, 3, 4, 9, 6]);
+    ///
+    /// assert_eq!(tensor.argmax(), [0, 1]);
+    /// ```
+    pub fn argmax(&self) -> [usize; R] {
+        let len = self.metadata.size();
+        let offset = unsafe { arg_extreme(len, 1, self.data.as_ptr(), Ordering::Greater) };
+        self.metadata.index_of(offset)
+    }
+
+    /// Returns the index of the first least element in `TotalOrder`, so the index of the first
+    /// negative NaN if any for floats, and of a positive NaN only if all the elements are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1.0, 9.0, 3.0, f64::NAN, -9.0, 6.0]);
+    ///
+    /// assert_eq!(tensor.argmin(), [1, 1]);
+    /// ```
+    pub fn argmin(&self) -> [usize; R] {
+        let len = self.metadata.size();
+        let offset = unsafe { arg_extreme(len, 1, self.data.as_ptr(), Ordering::Less) };
+        self.metadata.index_of(offset)
+    }
+
+    /// Returns for each lane along `axis` the position of its first greatest element in
+    /// `TotalOrder`, as `Tensor<usize, N>` with the dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 9, 3, 7, 2, 8]);
+    ///
+    /// let rows: Tensor<usize, 1> = tensor.argmax_axis(1);
+    /// let columns: Tensor<usize, 1> = tensor.argmax_axis(0);
+    ///
+    /// assert_eq!(rows.as_slice(), &[1, 2]);
+    /// assert_eq!(columns.as_slice(), &[1, 0, 1]);
+    /// ```
+    pub fn argmax_axis<const N: usize>(&self, axis: usize) -> Tensor<usize, N> {
+        self.arg_extreme_axis(axis, Ordering::Greater)
+    }
+
+    /// Returns for each lane along `axis` the position of its first least element in
+    /// `TotalOrder`, as `Tensor<usize, N>` with the dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    pub fn argmin_axis<const N: usize>(&self, axis: usize) -> Tensor<usize, N> {
+        self.arg_extreme_axis(axis, Ordering::Less)
+    }
+
+    /// Returns for each lane along `axis` the position of the first element for which no later
+    /// element compares as `ordering` to it.
+    fn arg_extreme_axis<const N: usize>(
+        &self,
+        axis: usize,
+        ordering: Ordering,
+    ) -> Tensor<usize, N> {
+        assert_valid_axis::<R>(axis);
+        assert_reduced_rank::<R, N>();
+
+        let metadata = TensorMetadata::new(self.metadata.remove_axis(axis));
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+        let a = self.data.as_ptr();
+
+        Tensor::make_with(metadata, |j| {
+            let (o, i) = (j / inner, j % inner);
+            unsafe { arg_extreme(len, inner, a.add(o * len * inner + i), ordering) }
+        })
+    }
+
+    /// Returns the `k` greatest elements (or least if `largest` is `false`) of each lane along
+    /// `axis` and their positions in the lane, as tensors whose dimension along `axis` is `k`.
+    ///
+    /// The selected elements are sorted, greatest first if `largest` is `true` and least first
+    /// otherwise, in `TotalOrder`, so positive NaN is treated as greater than any number and
+    /// negative NaN as less. Equal elements are ordered by position.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `k` is `0` or greater than the
+    /// dimension of `self` along `axis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 4], &[1.0, 4.0, 2.0, 3.0, 8.0, 5.0, f64::NAN, 7.0]);
+    ///
+    /// let (values, indices) = tensor.topk(2, 1, true);
+    ///
+    /// assert_eq!(values.shape(), &[2, 2]);
+    /// assert_eq!(values.as_slice()[..2], [4.0, 3.0]);
+    /// assert!(values.as_slice()[2].is_nan());
+    /// assert_eq!(values.as_slice()[3], 8.0);
+    /// assert_eq!(indices.as_slice(), &[1, 3, 2, 0]);
+    /// ```
+    pub fn topk(&self, k: usize, axis: usize, largest: bool) -> (Self, Tensor<usize, R>) {
+        assert_valid_axis::<R>(axis);
+
+        let (outer, len, inner) = self.metadata.split_at_axis(axis);
+
+        assert!(k > 0 && k <= len, "Invalid number of elements to select");
+
+        let mut dims = self.metadata.dims();
+        dims[axis] = k;
+
+        let a = self.data.as_ptr();
+
+        let mut positions: Vec<usize> = Vec::with_capacity(outer * k * inner);
+        let mut lane: Vec<usize> = Vec::with_capacity(len);
+
+        let mut o = 0;
+        while o < outer {
+            let mut i = 0;
+            while i < inner {
+                let base = unsafe { a.add(o * len * inner + i) };
+                let cmp = |x: &usize, y: &usize| {
+                    let ordering =
+                        unsafe { (*base.add(x * inner)).total_order(&*base.add(y * inner)) };
+                    let ordering = if largest {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    };
+                    ordering.then(x.cmp(y))
+                };
+
+                lane.clear();
+                lane.extend(0..len);
+                if k < len {
+                    lane.select_nth_unstable_by(k - 1, cmp);
+                }
+                lane[..k].sort_unstable_by(cmp);
+
+                positions.extend_from_slice(&lane[..k]);
+                i += 1;
+            }
+            o += 1;
+        }
+
+        // Positions were collected lane by lane, reorder them to the row-major order of the
+        // result.
+        let p = &positions;
+        let indices = Tensor::make_with(TensorMetadata::new(dims), |j| {
+            let (o, rest) = (j / (k * inner), j % (k * inner));
+            let (r, i) = (rest / inner, rest % inner);
+            p[(o * inner + i) * k + r]
+        });
+
+        let d = &indices.data;
+        let values = Tensor::make_with(indices.metadata, |j| {
+            let (o, i) = (j / (k * inner), j % inner);
+            unsafe { *a.add((o * len + *d.reference(j)) * inner + i) }
+        });
+
+        (values, indices)
+    }
+}
+
+#[cfg(test)]
+mod extrema_tests {
+    use super::*;
+
+    #[test]
+    fn test_argmax_argmin() {
+        let tensor = Tensor::from_slice([2, 2, 2], &[3, 8, 8, -1, 0, 2, -1, 5]);
+
+        assert_eq!(tensor.argmax(), [0, 0, 1]);
+        assert_eq!(tensor.argmin(), [0, 1, 1]);
+    }
+
+    #[test]
+    fn test_argmax_argmin_nan() {
+        let tensor = Tensor::from_slice([4], &[1.0, f32::NAN, 7.0, f32::NAN]);
+
+        assert_eq!(tensor.argmax(), [1]);
+        assert_eq!(tensor.argmin(), [0]);
+
+        let tensor = Tensor::new_set([2, 2], f64::NAN);
+
+        assert_eq!(tensor.argmin(), [0, 0]);
+    }
+
+    #[test]
+    fn test_argmax_argmin_axis() {
+        let tensor = Tensor::from_slice([2, 3, 2], &[1, 6, 5, 2, 3, 4, 9, 0, 8, 7, 9, 7]);
+
+        let argmax: Tensor<usize, 2> = tensor.argmax_axis(1);
+        let argmin: Tensor<usize, 2> = tensor.argmin_axis(0);
+
+        assert_eq!(argmax.shape(), &[2, 2]);
+        assert_eq!(argmax.as_slice(), &[1, 0, 0, 1]);
+        assert_eq!(argmin.shape(), &[3, 2]);
+        assert_eq!(argmin.as_slice(), &[0, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_argmax_axis_invalid_rank() {
+        let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+
+        let _: Tensor<usize, 2> = tensor.argmax_axis(0);
+    }
+
+    #[test]
+    fn test_topk() {
+        let tensor = Tensor::from_slice([2, 4], &[5, 1, 5, 3, 0, 9, 2, 9]);
+
+        let (values, indices) = tensor.topk(3, 1, true);
+
+        assert_eq!(values.shape(), &[2, 3]);
+        assert_eq!(values.as_slice(), &[5, 5, 3, 9, 9, 2]);
+        assert_eq!(indices.as_slice(), &[0, 2, 3, 1, 3, 2]);
+
+        let (values, indices) = tensor.topk(2, 1, false);
+
+        assert_eq!(values.as_slice(), &[1, 3, 0, 2]);
+        assert_eq!(indices.as_slice(), &[1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_topk_inner_axis() {
+        let tensor = Tensor::from_slice([3, 2], &[1.0, 6.0, f64::NAN, 2.0, 3.0, 4.0]);
+
+        let (values, indices) = tensor.topk(2, 0, false);
+
+        assert_eq!(values.shape(), &[2, 2]);
+        assert_eq!(values.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(indices.as_slice(), &[0, 1, 2, 2]);
+
+        let (values, indices) = tensor.topk(3, 0, true);
+
+        assert!(values.as_slice()[0].is_nan());
+        assert_eq!(values.as_slice()[1..], [6.0, 3.0, 4.0, 1.0, 2.0]);
+        assert_eq!(indices.as_slice(), &[1, 0, 2, 2, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of elements to select")]
+    fn test_topk_invalid_k() {
+        let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+
+        let _ = tensor.topk(3, 0, true);
+    }
+}
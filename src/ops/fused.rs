@@ -0,0 +1,260 @@
+use core::ops::{Add, Mul};
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+use crate::num::Float;
+
+/// Replaces `n` values of `r` by the result of `f` applied to them and the values of `a`.
+#[inline(always)]
+unsafe fn fused1<T, F>(n: usize, r: *mut T, a: *const T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let r_i = *r.add(i);
+            let a_i = *a.add(i);
+            r.add(i).write(f(r_i, a_i));
+        }
+        i += 1;
+    }
+}
+
+/// Replaces `n` values of `r` by the result of `f` applied to them and the values of `a` and `b`.
+#[inline(always)]
+unsafe fn fused2<T, F>(n: usize, r: *mut T, a: *const T, b: *const T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T, T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let r_i = *r.add(i);
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(f(r_i, a_i, b_i));
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy,
+{
+    /// Replaces in-place each element of `self` by the result of `f` applied to it and the
+    /// corresponding element of `x`.
+    fn fused1_inplace<F>(&mut self, x: &Self, f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_same_shape(self, x);
+
+        let len = self.metadata.size();
+        let r = self.data.as_ptr_mut();
+        let a = x.data.as_ptr();
+
+        unsafe { fused1(len, r, a, f) }
+    }
+
+    /// Computes `self += alpha * x` element-wise in a single pass.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `x` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut weights = Tensor::from_slice([2, 2], &[1.0, 2.0, 3.0, 4.0]);
+    /// let gradient = Tensor::from_slice([2, 2], &[10.0, 20.0, 30.0, 40.0]);
+    ///
+    /// weights.axpy(-0.5, &gradient);
+    ///
+    /// assert_eq!(weights.as_slice(), &[-4.0, -8.0, -12.0, -16.0]);
+    /// ```
+    pub fn axpy(&mut self, alpha: T, x: &Self)
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.fused1_inplace(x, |r, x| r + alpha * x)
+    }
+
+    /// Computes `self = scale * self + alpha * x` element-wise in a single pass.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `x` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([3], &[1, 2, 3]);
+    /// let x = Tensor::from_slice([3], &[4, 5, 6]);
+    ///
+    /// tensor.scale_add(2, 10, &x);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[42, 54, 66]);
+    /// ```
+    pub fn scale_add(&mut self, scale: T, alpha: T, x: &Self)
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.fused1_inplace(x, |r, x| scale * r + alpha * x)
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Float,
+{
+    /// Computes `self += a * b` element-wise in a single pass, with only one rounding error
+    /// for each element.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self`, `a` and `b` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([3], &[1.0, 2.0, 3.0]);
+    /// let a = Tensor::from_slice([3], &[2.0, 3.0, 4.0]);
+    /// let b = Tensor::from_slice([3], &[0.5, 1.0, -1.0]);
+    ///
+    /// tensor.fma(&a, &b);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[2.0, 5.0, -1.0]);
+    /// ```
+    pub fn fma(&mut self, a: &Self, b: &Self) {
+        assert_same_shape(self, a);
+        assert_same_shape(self, b);
+
+        let len = self.metadata.size();
+        let r = self.data.as_ptr_mut();
+        let a = a.data.as_ptr();
+        let b = b.data.as_ptr();
+
+        unsafe { fused2(len, r, a, b, |r, a, b| a.mul_add(b, r)) }
+    }
+
+    /// Linearly interpolates in-place between `self` and `other` element-wise in a single pass,
+    /// computing `self + t * (other - self)`.
+    ///
+    /// The endpoints are exact: `t` of `0` leaves `self` unchanged and `t` of `1` results in
+    /// `other`, even where `other - self` rounds or overflows.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut average = Tensor::from_slice([3], &[0.0, 10.0, 4.0]);
+    /// let value = Tensor::from_slice([3], &[10.0, 10.0, 0.0]);
+    ///
+    /// average.lerp(&value, 0.25);
+    ///
+    /// assert_eq!(average.as_slice(), &[2.5, 10.0, 3.0]);
+    /// ```
+    pub fn lerp(&mut self, other: &Self, t: T) {
+        self.fused1_inplace(other, |r, o| {
+            if t == T::ZERO {
+                r
+            } else if t == T::ONE {
+                o
+            } else {
+                t.mul_add(o - r, r)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod fused_tests {
+    use super::*;
+
+    #[test]
+    fn test_axpy() {
+        let mut tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let x = Tensor::from_slice([2, 2], &[1, -1, 2, -2]);
+
+        tensor.axpy(3, &x);
+
+        assert_eq!(tensor.as_slice(), &[4, -1, 9, -2]);
+        assert_eq!(x.as_slice(), &[1, -1, 2, -2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axpy_shape_mismatch() {
+        let mut tensor = Tensor::new_set([2, 2], 1.0);
+        let x = Tensor::new_set([2, 3], 1.0);
+
+        tensor.axpy(1.0, &x);
+    }
+
+    #[test]
+    fn test_scale_add() {
+        let mut tensor = Tensor::from_slice([2], &[1.0f32, -2.0]);
+        let x = Tensor::from_slice([2], &[4.0f32, 8.0]);
+
+        tensor.scale_add(0.5, 0.25, &x);
+
+        assert_eq!(tensor.as_slice(), &[1.5, 1.0]);
+    }
+
+    #[test]
+    fn test_fma_single_rounding() {
+        // 1 + 2^-30 squared is 1 + 2^-29 + 2^-60, where the last term is lost when rounded
+        // before the addition of -(1 + 2^-29).
+        let x = 1.0 + f64::EPSILON.sqrt() / 2f64.powi(4);
+        let mut tensor = Tensor::from_slice([1], &[-(x * x)]);
+        let a = Tensor::from_slice([1], &[x]);
+
+        tensor.fma(&a, &a);
+
+        assert_ne!(tensor.as_slice()[0], 0.0);
+        assert_eq!(tensor.as_slice()[0], x.mul_add(x, -(x * x)));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let mut tensor = Tensor::from_slice([2, 2], &[0.0, 1.0, -2.0, 8.0]);
+        let other = Tensor::from_slice([2, 2], &[4.0, 1.0, 2.0, 0.0]);
+
+        let mut start = tensor.clone();
+        start.lerp(&other, 0.0);
+        assert_eq!(start.as_slice(), tensor.as_slice());
+
+        let mut end = tensor.clone();
+        end.lerp(&other, 1.0);
+        assert_eq!(end.as_slice(), other.as_slice());
+
+        tensor.lerp(&other, 0.5);
+        assert_eq!(tensor.as_slice(), &[2.0, 1.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_lerp_exact_endpoints() {
+        // `other - self` rounds for the first pair and overflows for the second.
+        let tensor = Tensor::from_slice([2], &[1e16, f64::MAX]);
+        let other = Tensor::from_slice([2], &[1.0, -f64::MAX]);
+
+        let mut start = tensor.clone();
+        start.lerp(&other, 0.0);
+        assert_eq!(start.as_slice(), tensor.as_slice());
+
+        let mut end = tensor.clone();
+        end.lerp(&other, 1.0);
+        assert_eq!(end.as_slice(), other.as_slice());
+    }
+}
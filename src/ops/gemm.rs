@@ -0,0 +1,181 @@
+use crate::num::{Accumulator, Float, Summation};
+
+/// Strided view of a matrix: element `(i, j)` is at `ptr + i * row_stride + j * col_stride`.
+#[derive(Clone, Copy)]
+pub(crate) struct MatrixRef<T> {
+    pub(crate) ptr: *const T,
+    pub(crate) row_stride: usize,
+    pub(crate) col_stride: usize,
+}
+
+impl<T> MatrixRef<T> {
+    /// View of a row-major matrix with `n` columns.
+    pub(crate) const fn row_major(ptr: *const T, n: usize) -> Self {
+        Self {
+            ptr,
+            row_stride: n,
+            col_stride: 1,
+        }
+    }
+
+    /// View of the transpose of a row-major matrix with `n` columns.
+    pub(crate) const fn transposed(ptr: *const T, n: usize) -> Self {
+        Self {
+            ptr,
+            row_stride: 1,
+            col_stride: n,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn at(&self, i: usize, j: usize) -> *const T {
+        unsafe { self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+}
+
+/// Computes the matrix product `C = A * B`, or `C += A * B` if `accumulate`, where `A` is `m x k`,
+/// `B` is `k x n` and `C` is a contiguous row-major `m x n` matrix.
+///
+/// Products are accumulated with the global summation strategy. `Naive` sums are accumulated in
+/// `T`, with the loops ordered so that the innermost one reads contiguous memory for row-major
+/// `B`, or for row-major `A` and transposed `B`. The other strategies accumulate each value of `C`
+/// as `f64`, from its previous value if `accumulate`, and round it to `T` once.
+pub(crate) unsafe fn gemm<T>(
+    m: usize,
+    n: usize,
+    k: usize,
+    a: MatrixRef<T>,
+    b: MatrixRef<T>,
+    c: *mut T,
+    accumulate: bool,
+) where
+    T: Float,
+{
+    let summation = Summation::global();
+
+    unsafe {
+        if summation != Summation::Naive {
+            // One accumulator per row, reset for each value.
+            let mut i = 0;
+            while i < m {
+                let mut sum = Accumulator::new(summation);
+                let mut j = 0;
+                while j < n {
+                    let c_ij = c.add(i * n + j);
+                    sum.reset();
+                    if accumulate {
+                        sum.add((*c_ij).to_f64());
+                    }
+
+                    let mut p = 0;
+                    while p < k {
+                        sum.add((*a.at(i, p)).to_f64() * (*b.at(p, j)).to_f64());
+                        p += 1;
+                    }
+
+                    c_ij.write(T::from_f64(sum.sum()));
+                    j += 1;
+                }
+                i += 1;
+            }
+            return;
+        }
+
+        if !accumulate {
+            let mut i = 0;
+            while i < m {
+                let mut j = 0;
+                while j < n {
+                    c.add(i * n + j).write(T::ZERO);
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+
+        if b.col_stride == 1 {
+            // Row-major B: C[i, ..] += A[i, p] * B[p, ..].
+            let mut i = 0;
+            while i < m {
+                let c_i = c.add(i * n);
+                let mut p = 0;
+                while p < k {
+                    let a_ip = *a.at(i, p);
+                    let b_p = b.at(p, 0);
+                    let mut j = 0;
+                    while j < n {
+                        *c_i.add(j) += a_ip * *b_p.add(j);
+                        j += 1;
+                    }
+                    p += 1;
+                }
+                i += 1;
+            }
+        } else {
+            // Dot products of the rows of A with the columns of B.
+            let mut i = 0;
+            while i < m {
+                let mut j = 0;
+                while j < n {
+                    let mut sum = T::ZERO;
+                    let mut p = 0;
+                    while p < k {
+                        sum += *a.at(i, p) * *b.at(p, j);
+                        p += 1;
+                    }
+                    *c.add(i * n + j) += sum;
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod gemm_tests {
+    use super::*;
+
+    #[test]
+    fn test_gemm() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+        let mut c = [0.0; 4];
+
+        // [2 x 3] * [3 x 2]
+        unsafe {
+            gemm(
+                2,
+                2,
+                3,
+                MatrixRef::row_major(a.as_ptr(), 3),
+                MatrixRef::row_major(b.as_ptr(), 2),
+                c.as_mut_ptr(),
+                false,
+            );
+        }
+
+        assert_eq!(c, [58.0, 64.0, 139.0, 154.0]);
+    }
+
+    #[test]
+    fn test_gemm_transposed_accumulate() {
+        let a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut c = [1.0f32; 4];
+
+        // [2 x 3] * [2 x 3]^T, added to the ones of C.
+        unsafe {
+            gemm(
+                2,
+                2,
+                3,
+                MatrixRef::row_major(a.as_ptr(), 3),
+                MatrixRef::transposed(a.as_ptr(), 3),
+                c.as_mut_ptr(),
+                true,
+            );
+        }
+
+        assert_eq!(c, [15.0, 33.0, 33.0, 78.0]);
+    }
+}
@@ -0,0 +1,355 @@
+use crate::Tensor;
+use crate::assertions::{assert_reduced_rank, assert_same_shape, assert_valid_axis};
+use crate::metadata::TensorMetadata;
+
+impl<T, const R: usize> Tensor<T, R> {
+    /// Applies `f` to each element and returns the results as new `Tensor<U, R>` with the same
+    /// shape, without affecting the original instance.
+    ///
+    /// If `f` panics, the already computed values are dropped and the memory is released.
+    ///
+    /// # Panics
+    /// This method will panic if `U` is ZST.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[1, -2, 3, -4]);
+    ///
+    /// let result = tensor.map(|x| *x > 0);
+    ///
+    /// assert_eq!(result.as_slice(), &[true, false, true, false]);
+    /// ```
+    pub fn map<U, F>(&self, mut f: F) -> Tensor<U, R>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let a = &self.data;
+        Tensor::make_with(self.metadata, |i| f(unsafe { a.reference(i) }))
+    }
+
+    /// Applies `f` to each element in-place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 2], &[1.0, f64::NAN, 3.0, -4.0]);
+    ///
+    /// tensor.map_inplace(|x| if x.is_nan() { *x = 0.0 });
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1.0, 0.0, 3.0, -4.0]);
+    /// ```
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let len = self.metadata.size();
+        let a = &mut self.data;
+
+        let mut i = 0;
+        while i < len {
+            f(unsafe { a.reference_mut(i) });
+            i += 1;
+        }
+    }
+
+    /// Applies `f` to the pairs of elements of `self` and `other` and returns the results as new
+    /// `Tensor<U, R>` with the same shape, without affecting the original instances.
+    ///
+    /// If `f` panics, the already computed values are dropped and the memory is released.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match, or if `U` is
+    /// ZST.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([3], &[1, 5, 3]);
+    /// let b = Tensor::from_slice([3], &[4, 2, 3]);
+    ///
+    /// let result = a.zip_with(&b, |a, b| a.max(b) - a.min(b));
+    ///
+    /// assert_eq!(result.as_slice(), &[3, 3, 0]);
+    /// ```
+    pub fn zip_with<V, U, F>(&self, other: &Tensor<V, R>, mut f: F) -> Tensor<U, R>
+    where
+        F: FnMut(&T, &V) -> U,
+    {
+        assert_same_shape(self, other);
+
+        let a = &self.data;
+        let b = &other.data;
+        Tensor::make_with(self.metadata, |i| unsafe {
+            f(a.reference(i), b.reference(i))
+        })
+    }
+
+    /// Applies `f` to the triples of elements of `self`, `b` and `c` and returns the results as
+    /// new `Tensor<U, R>` with the same shape, without affecting the original instances.
+    ///
+    /// If `f` panics, the already computed values are dropped and the memory is released.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self`, `b` and `c` do not match, or if `U`
+    /// is ZST.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let x = Tensor::from_slice([3], &[1.0f64, 2.0, 3.0]);
+    /// let lo = Tensor::from_slice([3], &[2.0, 0.0, 0.0]);
+    /// let hi = Tensor::from_slice([3], &[4.0, 4.0, 2.0]);
+    ///
+    /// let result = x.zip3_with(&lo, &hi, |x, lo, hi| x.max(*lo).min(*hi));
+    ///
+    /// assert_eq!(result.as_slice(), &[2.0, 2.0, 2.0]);
+    /// ```
+    pub fn zip3_with<V, W, U, F>(
+        &self,
+        b: &Tensor<V, R>,
+        c: &Tensor<W, R>,
+        mut f: F,
+    ) -> Tensor<U, R>
+    where
+        F: FnMut(&T, &V, &W) -> U,
+    {
+        assert_same_shape(self, b);
+        assert_same_shape(self, c);
+
+        let a_data = &self.data;
+        let b_data = &b.data;
+        let c_data = &c.data;
+        Tensor::make_with(self.metadata, |i| unsafe {
+            f(
+                a_data.reference(i),
+                b_data.reference(i),
+                c_data.reference(i),
+            )
+        })
+    }
+
+    /// Folds all elements in row-major order into an accumulator, starting with `init`, and
+    /// returns the final accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+    ///
+    /// let sum = tensor.fold(0, |acc, x| acc + x);
+    ///
+    /// assert_eq!(sum, 10);
+    /// ```
+    pub fn fold<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, &T) -> A,
+    {
+        let len = self.metadata.size();
+        let a = &self.data;
+
+        let mut acc = init;
+        let mut i = 0;
+        while i < len {
+            acc = f(acc, unsafe { a.reference(i) });
+            i += 1;
+        }
+        acc
+    }
+
+    /// Folds the elements along `axis` into accumulators, each starting with a clone of `init`,
+    /// and returns the final accumulators as new `Tensor<A, N>`, where `N` is `R - 1`.
+    ///
+    /// The dimensions of the result are the dimensions of `self` without `axis`.
+    ///
+    /// If `f` panics, the already computed values are dropped and the memory is released.
+    ///
+    /// # Panics
+    /// This method will panic when:
+    /// - `axis` is out of bounds.
+    /// - `N` is not `R - 1`.
+    /// - `A` is ZST.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let rows: Tensor<i32, 1> = tensor.fold_axis(1, 0, |acc, x| acc + x);
+    /// let columns: Tensor<i32, 1> = tensor.fold_axis(0, 0, |acc, x| acc + x);
+    ///
+    /// assert_eq!(rows.as_slice(), &[6, 15]);
+    /// assert_eq!(columns.as_slice(), &[5, 7, 9]);
+    /// ```
+    pub fn fold_axis<const N: usize, A, F>(&self, axis: usize, init: A, mut f: F) -> Tensor<A, N>
+    where
+        A: Clone,
+        F: FnMut(A, &T) -> A,
+    {
+        assert_valid_axis::<R>(axis);
+        assert_reduced_rank::<R, N>();
+
+        let metadata = TensorMetadata::new(self.metadata.remove_axis(axis));
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+        let a = &self.data;
+
+        Tensor::make_with(metadata, |j| {
+            let (o, i) = (j / inner, j % inner);
+            let base = o * len * inner + i;
+
+            let mut acc = init.clone();
+            let mut k = 0;
+            while k < len {
+                acc = f(acc, unsafe { a.reference(base + k * inner) });
+                k += 1;
+            }
+            acc
+        })
+    }
+}
+
+#[cfg(test)]
+mod map_tests {
+    use super::*;
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_map() {
+        let tensor = Tensor::from_slice([2, 2], &[1u8, 2, 3, 4]);
+
+        let result = tensor.map(|x| *x as f64 / 2.0);
+
+        assert_eq!(result.shape(), &[2, 2]);
+        assert_eq!(result.as_slice(), &[0.5, 1.0, 1.5, 2.0]);
+    }
+
+    #[test]
+    fn test_map_non_trivial() {
+        let tensor = Tensor::from_slice([3], &[1, 2, 3]);
+
+        let result = tensor.map(|x| x.to_string());
+
+        assert_eq!(result.as_slice(), &["1", "2", "3"]);
+    }
+
+    #[derive(Debug)]
+    struct DropCounter {
+        count: Rc<RefCell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            *self.count.borrow_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn test_map_unwind() {
+        let tensor = Tensor::from_slice([2, 3], &[0, 1, 2, 3, 4, 5]);
+        let drop_count = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&drop_count);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tensor.map(|x| {
+                if *x == 4 {
+                    panic!("Mapping panicked");
+                }
+                DropCounter {
+                    count: Rc::clone(&counter),
+                }
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(*drop_count.borrow(), 4);
+    }
+
+    #[test]
+    fn test_map_inplace() {
+        let mut tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+
+        tensor.map_inplace(|x| *x *= 10);
+
+        assert_eq!(tensor.as_slice(), &[10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let b = Tensor::from_slice([2, 2], &[1.5, 1.5, 1.5, 1.5]);
+
+        let result = a.zip_with(&b, |a, b| *a as f64 * b);
+
+        assert_eq!(result.as_slice(), &[1.5, 3.0, 4.5, 6.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zip_with_invalid_shape() {
+        let a = Tensor::new_set([2, 2], 1);
+        let b = Tensor::new_set([2, 3], 1);
+
+        let _ = a.zip_with(&b, |a, b| a + b);
+    }
+
+    #[test]
+    fn test_zip3_with() {
+        let mask = Tensor::from_slice([3], &[true, false, true]);
+        let a = Tensor::from_slice([3], &[1, 2, 3]);
+        let b = Tensor::from_slice([3], &[4, 5, 6]);
+
+        let result = mask.zip3_with(&a, &b, |m, a, b| if *m { *a } else { *b });
+
+        assert_eq!(result.as_slice(), &[1, 5, 3]);
+    }
+
+    #[test]
+    fn test_fold() {
+        let tensor = Tensor::from_slice([2, 2], &[3, 1, 4, 1]);
+
+        let max = tensor.fold(i32::MIN, |acc, x| acc.max(*x));
+
+        assert_eq!(max, 4);
+    }
+
+    #[test]
+    fn test_fold_axis() {
+        let tensor = Tensor::from_slice([2, 3, 2], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+        let result: Tensor<i32, 2> = tensor.fold_axis(1, 0, |acc, x| acc + x);
+
+        assert_eq!(result.shape(), &[2, 2]);
+        assert_eq!(result.as_slice(), &[9, 12, 27, 30]);
+    }
+
+    #[test]
+    fn test_fold_axis_to_zero_rank() {
+        let tensor = Tensor::from_slice([4], &[1, 2, 3, 4]);
+
+        let result: Tensor<i32, 0> = tensor.fold_axis(0, 1, |acc, x| acc * x);
+
+        assert_eq!(result.get(&[]), &24);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold_axis_invalid_rank() {
+        let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+
+        let _: Tensor<i32, 2> = tensor.fold_axis(0, 0, |acc, x| acc + x);
+    }
+}
@@ -0,0 +1,370 @@
+use core::hint::unreachable_unchecked;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+use crate::num::Float;
+
+/// Applies `f` to `n` values of `a` and writes result to `r`.
+#[inline(always)]
+unsafe fn unary<T, F>(n: usize, a: *const T, r: *mut T, f: F)
+where
+    T: Copy,
+    F: Fn(T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(f(a_i));
+        }
+        i += 1;
+    }
+}
+
+/// Applies `f` to `n` values of `a` and `b` and writes result to `r`.
+#[inline(always)]
+unsafe fn binary<T, F>(n: usize, a: *const T, b: *const T, r: *mut T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(f(a_i, b_i));
+        }
+        i += 1;
+    }
+}
+
+/// Generates the new, in-place and owned-consuming variants of element-wise functions of one
+/// argument.
+macro_rules! unary_fns {
+    ($($name:ident, $inplace:ident, $into:ident, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Computes ", $what, " of each element and returns new `Tensor<T, R>`")]
+            #[doc = "without affecting the original instance."]
+            #[inline]
+            pub fn $name(&self) -> Self {
+                self.unary_new(T::$name)
+            }
+
+            #[doc = concat!("Computes ", $what, " of each element in-place.")]
+            #[inline]
+            pub fn $inplace(&mut self) {
+                self.unary_inplace(T::$name)
+            }
+
+            #[doc = concat!("Computes ", $what, " of each element in-place and returns `self`.")]
+            #[inline]
+            pub fn $into(mut self) -> Self {
+                self.unary_inplace(T::$name);
+                self
+            }
+        )*
+    };
+}
+
+/// Generates the new, in-place and owned-consuming variants of element-wise functions of two
+/// tensors.
+macro_rules! binary_fns {
+    ($($name:ident, $inplace:ident, $into:ident, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Computes ", $what, " element-wise and returns new `Tensor<T, R>`")]
+            #[doc = "without affecting the original instances."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            #[inline]
+            pub fn $name(&self, other: &Self) -> Self {
+                self.binary_new(other, T::$name)
+            }
+
+            #[doc = concat!("Computes ", $what, " element-wise in-place.")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            #[inline]
+            pub fn $inplace(&mut self, other: &Self) {
+                self.binary_inplace(other, T::$name)
+            }
+
+            #[doc = concat!("Computes ", $what, " element-wise in-place and returns `self`.")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            #[inline]
+            pub fn $into(mut self, other: &Self) -> Self {
+                self.binary_inplace(other, T::$name);
+                self
+            }
+        )*
+    };
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Float,
+{
+    /// Applies `f` to each element and returns the result as new tensor.
+    fn unary_new<F>(&self, f: F) -> Self
+    where
+        F: Fn(T) -> T,
+    {
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            unary(len, a, output.as_ptr_mut(), f);
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+
+    /// Applies `f` to each element in-place.
+    fn unary_inplace<F>(&mut self, f: F)
+    where
+        F: Fn(T) -> T,
+    {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        unsafe { unary(len, a, a, f) }
+    }
+
+    /// Applies `f` to each pair of elements and returns the result as new tensor.
+    fn binary_new<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::<T>::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            binary(len, a, b, output.as_ptr_mut(), f);
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+
+    /// Applies `f` to each pair of elements in-place.
+    fn binary_inplace<F>(&mut self, other: &Self, f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+        unsafe { binary(len, a, b, a, f) }
+    }
+
+    unary_fns! {
+        exp, exp_inplace, into_exp, "`e^x`";
+        exp2, exp2_inplace, into_exp2, "`2^x`";
+        ln, ln_inplace, into_ln, "the natural logarithm";
+        log2, log2_inplace, into_log2, "the base 2 logarithm";
+        log10, log10_inplace, into_log10, "the base 10 logarithm";
+        sqrt, sqrt_inplace, into_sqrt, "the square root";
+        cbrt, cbrt_inplace, into_cbrt, "the cube root";
+        sin, sin_inplace, into_sin, "the sine";
+        cos, cos_inplace, into_cos, "the cosine";
+        tan, tan_inplace, into_tan, "the tangent";
+        asin, asin_inplace, into_asin, "the arcsine";
+        acos, acos_inplace, into_acos, "the arccosine";
+        atan, atan_inplace, into_atan, "the arctangent";
+        sinh, sinh_inplace, into_sinh, "the hyperbolic sine";
+        cosh, cosh_inplace, into_cosh, "the hyperbolic cosine";
+        tanh, tanh_inplace, into_tanh, "the hyperbolic tangent";
+        abs, abs_inplace, into_abs, "the absolute value";
+        signum, signum_inplace, into_signum, "the sign";
+        floor, floor_inplace, into_floor, "the largest integer less than or equal";
+        ceil, ceil_inplace, into_ceil, "the smallest integer greater than or equal";
+        round, round_inplace, into_round, "the nearest integer, rounding half-way away from `0`,";
+        trunc, trunc_inplace, into_trunc, "the integer part";
+        recip, recip_inplace, into_recip, "the reciprocal `1/x`";
+    }
+
+    binary_fns! {
+        atan2, atan2_inplace, into_atan2, "the four quadrant arctangent of `self` and `other`";
+        hypot, hypot_inplace, into_hypot, "the length of the hypotenuse with legs `self` and `other`";
+    }
+
+    /// Raises each element to the integer power `n` and returns new `Tensor<T, R>` without
+    /// affecting the original instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 2], 3.0);
+    ///
+    /// let result = tensor.powi(2);
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &9.0);
+    /// assert_eq!(result.get(&[1, 1]), &9.0);
+    /// ```
+    #[inline]
+    pub fn powi(&self, n: i32) -> Self {
+        self.unary_new(|x| x.powi(n))
+    }
+
+    /// Raises each element to the integer power `n` in-place.
+    #[inline]
+    pub fn powi_inplace(&mut self, n: i32) {
+        self.unary_inplace(|x| x.powi(n))
+    }
+
+    /// Raises each element to the integer power `n` in-place and returns `self`.
+    #[inline]
+    pub fn into_powi(mut self, n: i32) -> Self {
+        self.unary_inplace(|x| x.powi(n));
+        self
+    }
+
+    /// Raises each element to the floating-point power `n` and returns new `Tensor<T, R>`
+    /// without affecting the original instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 2], 4.0);
+    ///
+    /// let result = tensor.powf(0.5);
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &2.0);
+    /// assert_eq!(result.get(&[1, 1]), &2.0);
+    /// ```
+    #[inline]
+    pub fn powf(&self, n: T) -> Self {
+        self.unary_new(|x| x.powf(n))
+    }
+
+    /// Raises each element to the floating-point power `n` in-place.
+    #[inline]
+    pub fn powf_inplace(&mut self, n: T) {
+        self.unary_inplace(|x| x.powf(n))
+    }
+
+    /// Raises each element to the floating-point power `n` in-place and returns `self`.
+    #[inline]
+    pub fn into_powf(mut self, n: T) -> Self {
+        self.unary_inplace(|x| x.powf(n));
+        self
+    }
+}
+
+#[cfg(test)]
+mod math_tests {
+    use super::*;
+
+    #[test]
+    fn test_unary_new() {
+        let tensor = Tensor::from_slice([2, 2], &[1.0, 4.0, 9.0, 16.0]);
+
+        let result = tensor.sqrt();
+
+        assert_eq!(result.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(tensor.as_slice(), &[1.0, 4.0, 9.0, 16.0]);
+    }
+
+    #[test]
+    fn test_unary_inplace() {
+        let mut tensor = Tensor::from_slice([2, 2], &[-1.5f32, 2.5, -3.0, 0.0]);
+
+        tensor.abs_inplace();
+
+        assert_eq!(tensor.as_slice(), &[1.5, 2.5, 3.0, 0.0]);
+    }
+
+    #[test]
+    fn test_unary_into() {
+        let tensor = Tensor::from_slice([3], &[0.0, 1.0, 2.0]);
+
+        let result = tensor.into_exp().into_ln();
+
+        assert!((result.get(&[0]) - 0.0).abs() < 1e-12);
+        assert!((result.get(&[1]) - 1.0).abs() < 1e-12);
+        assert!((result.get(&[2]) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let tensor = Tensor::from_slice([4], &[-1.5, -0.5, 0.5, 1.7]);
+
+        assert_eq!(tensor.floor().as_slice(), &[-2.0, -1.0, 0.0, 1.0]);
+        assert_eq!(tensor.ceil().as_slice(), &[-1.0, -0.0, 1.0, 2.0]);
+        assert_eq!(tensor.round().as_slice(), &[-2.0, -1.0, 1.0, 2.0]);
+        assert_eq!(tensor.trunc().as_slice(), &[-1.0, -0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_binary() {
+        let a = Tensor::from_slice([2], &[3.0, 5.0]);
+        let b = Tensor::from_slice([2], &[4.0, 12.0]);
+
+        assert_eq!(a.hypot(&b).as_slice(), &[5.0, 13.0]);
+
+        let mut y = Tensor::from_slice([2], &[1.0, -1.0]);
+        let x = Tensor::from_slice([2], &[0.0, 0.0]);
+        y.atan2_inplace(&x);
+
+        assert_eq!(
+            y.as_slice(),
+            &[core::f64::consts::FRAC_PI_2, -core::f64::consts::FRAC_PI_2]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_binary_invalid_shape() {
+        let a = Tensor::new_set([2, 2], 1.0);
+        let b = Tensor::new_set([2, 3], 1.0);
+
+        let _ = a.hypot(&b);
+    }
+
+    #[test]
+    fn test_powers() {
+        let tensor = Tensor::from_slice([3], &[1.0f32, 2.0, 3.0]);
+
+        assert_eq!(tensor.powi(3).as_slice(), &[1.0, 8.0, 27.0]);
+        assert_eq!(tensor.into_powf(2.0).as_slice(), &[1.0, 4.0, 9.0]);
+    }
+}
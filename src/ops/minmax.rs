@@ -0,0 +1,388 @@
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+use crate::num::Float;
+
+/// Returns the greater of `a` and `b`, or the value that is not comparable to itself if any.
+#[inline(always)]
+pub(super) fn maximum<T: PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else if a <= b {
+        b
+    } else if a.partial_cmp(&a).is_none() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the lesser of `a` and `b`, or the value that is not comparable to itself if any.
+#[inline(always)]
+pub(super) fn minimum<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        a
+    } else if a >= b {
+        b
+    } else if a.partial_cmp(&a).is_none() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the greater of `a` and `b`, ignoring NaN if only one of them is NaN.
+#[inline(always)]
+fn fmax<T: Float>(a: T, b: T) -> T {
+    if a.is_nan() || a < b { b } else { a }
+}
+
+/// Returns the lesser of `a` and `b`, ignoring NaN if only one of them is NaN.
+#[inline(always)]
+fn fmin<T: Float>(a: T, b: T) -> T {
+    if a.is_nan() || a > b { b } else { a }
+}
+
+/// Restricts `x` to the interval `[lo, hi]`. NaN is returned unchanged.
+#[inline(always)]
+fn clamp<T: PartialOrd>(x: T, lo: T, hi: T) -> T {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+
+/// Applies `f` to `n` pairs of values of `a` and `b`, and writes result to `r`.
+#[inline(always)]
+unsafe fn binary<T, F>(n: usize, a: *const T, b: *const T, r: *mut T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(f(a_i, b_i));
+        }
+        i += 1;
+    }
+}
+
+/// Restricts `n` values of `a` to the intervals given by `lo` and `hi`, and writes result to `r`.
+#[inline(always)]
+unsafe fn clamp_between<T>(n: usize, a: *const T, lo: *const T, hi: *const T, r: *mut T)
+where
+    T: Copy + PartialOrd,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(clamp(a_i, *lo.add(i), *hi.add(i)));
+        }
+        i += 1;
+    }
+}
+
+/// Asserts that `lo` is less than or equal to `hi`.
+#[inline(always)]
+fn assert_valid_bounds<T: PartialOrd>(lo: &T, hi: &T) {
+    assert!(lo <= hi, "Invalid clamp bounds");
+}
+
+/// Generates the new and in-place variants of an element-wise function of two tensors.
+macro_rules! binary_fns {
+    ($($name:ident, $inplace:ident, $f:ident, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Computes ", $what, " of `self` and `other` element-wise and returns")]
+            #[doc = "new `Tensor<T, R>` without affecting the original instances."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            #[inline]
+            pub fn $name(&self, other: &Self) -> Self {
+                self.minmax_new(other, $f)
+            }
+
+            #[doc = concat!("Computes ", $what, " of `self` and `other` element-wise in-place.")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if the dimensions of `self` and `other` do not match."]
+            #[inline]
+            pub fn $inplace(&mut self, other: &Self) {
+                self.minmax_inplace(other, $f)
+            }
+        )*
+    };
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + PartialOrd,
+{
+    /// Applies `f` to each pair of elements and returns the result as new tensor.
+    fn minmax_new<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_same_shape(self, other);
+
+        let a = &self.data;
+        let b = &other.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            f(*a.reference(i), *b.reference(i))
+        })
+    }
+
+    /// Applies `f` to each pair of elements in-place.
+    fn minmax_inplace<F>(&mut self, other: &Self, f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+        unsafe { binary(len, a, b, a, f) }
+    }
+
+    /// Asserts that `lo` and `hi` have the same shape as `self`, and that each value of `lo` is
+    /// less than or equal to the corresponding value of `hi`.
+    fn assert_valid_bounds_tensor(&self, lo: &Self, hi: &Self) {
+        assert_same_shape(self, lo);
+        assert_same_shape(self, hi);
+
+        let len = self.metadata.size();
+        let mut i = 0;
+        while i < len {
+            unsafe { assert_valid_bounds(lo.data.reference(i), hi.data.reference(i)) };
+            i += 1;
+        }
+    }
+
+    binary_fns! {
+        maximum, maximum_inplace, maximum, "the maximum";
+        minimum, minimum_inplace, minimum, "the minimum";
+    }
+
+    /// Restricts each element to the interval `[lo, hi]` and returns the result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// NaN values remain NaN.
+    ///
+    /// # Panics
+    /// This method will panic if `lo` is greater than `hi`, or if either of them is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[-3.0, 0.5, 2.0, 7.0]);
+    ///
+    /// let result = tensor.clamp(0.0, 1.0);
+    ///
+    /// assert_eq!(result.as_slice(), &[0.0, 0.5, 1.0, 1.0]);
+    /// ```
+    pub fn clamp(&self, lo: T, hi: T) -> Self {
+        assert_valid_bounds(&lo, &hi);
+
+        let a = &self.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe { clamp(*a.reference(i), lo, hi) })
+    }
+
+    /// Restricts each element to the interval `[lo, hi]` in-place.
+    ///
+    /// NaN values remain NaN.
+    ///
+    /// # Panics
+    /// This method will panic if `lo` is greater than `hi`, or if either of them is NaN.
+    pub fn clamp_inplace(&mut self, lo: T, hi: T) {
+        assert_valid_bounds(&lo, &hi);
+
+        let len = self.metadata.size();
+        let mut i = 0;
+        while i < len {
+            unsafe {
+                let x = self.data.reference_mut(i);
+                *x = clamp(*x, lo, hi);
+            }
+            i += 1;
+        }
+    }
+
+    /// Restricts each element to the interval given by the corresponding elements of `lo` and
+    /// `hi` and returns the result as new `Tensor<T, R>`, without affecting the original
+    /// instances.
+    ///
+    /// NaN values remain NaN.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self`, `lo` and `hi` do not match, or if any
+    /// value of `lo` is greater than the corresponding value of `hi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([3], &[5, 5, 5]);
+    /// let lo = Tensor::from_slice([3], &[0, 6, 0]);
+    /// let hi = Tensor::from_slice([3], &[10, 8, 4]);
+    ///
+    /// let result = tensor.clamp_tensor(&lo, &hi);
+    ///
+    /// assert_eq!(result.as_slice(), &[5, 6, 4]);
+    /// ```
+    pub fn clamp_tensor(&self, lo: &Self, hi: &Self) -> Self {
+        self.assert_valid_bounds_tensor(lo, hi);
+
+        let a = &self.data;
+        let l = &lo.data;
+        let h = &hi.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            clamp(*a.reference(i), *l.reference(i), *h.reference(i))
+        })
+    }
+
+    /// Restricts each element in-place to the interval given by the corresponding elements of
+    /// `lo` and `hi`.
+    ///
+    /// NaN values remain NaN. The bounds are validated before any element is modified.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self`, `lo` and `hi` do not match, or if any
+    /// value of `lo` is greater than the corresponding value of `hi`.
+    pub fn clamp_tensor_inplace(&mut self, lo: &Self, hi: &Self) {
+        self.assert_valid_bounds_tensor(lo, hi);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        unsafe { clamp_between(len, a, lo.data.as_ptr(), hi.data.as_ptr(), a) }
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Float,
+{
+    binary_fns! {
+        fmax, fmax_inplace, fmax, "the NaN-ignoring maximum";
+        fmin, fmin_inplace, fmin, "the NaN-ignoring minimum";
+    }
+}
+
+#[cfg(test)]
+mod minmax_tests {
+    use super::*;
+
+    #[test]
+    fn test_maximum_minimum() {
+        let a = Tensor::from_slice([2, 2], &[1, 5, -3, 4]);
+        let b = Tensor::from_slice([2, 2], &[2, 4, -3, 0]);
+
+        assert_eq!(a.maximum(&b).as_slice(), &[2, 5, -3, 4]);
+        assert_eq!(a.minimum(&b).as_slice(), &[1, 4, -3, 0]);
+    }
+
+    #[test]
+    fn test_maximum_inplace() {
+        let mut a = Tensor::from_slice([3], &[1.0, -2.0, 3.0]);
+        let zero = Tensor::new_set([3], 0.0);
+
+        a.maximum_inplace(&zero);
+
+        assert_eq!(a.as_slice(), &[1.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn test_maximum_nan_propagation() {
+        let a = Tensor::from_slice([3], &[f64::NAN, 1.0, 2.0]);
+        let b = Tensor::from_slice([3], &[1.0, f64::NAN, 0.0]);
+
+        let max = a.maximum(&b);
+        let min = a.minimum(&b);
+
+        assert!(max.as_slice()[0].is_nan() && max.as_slice()[1].is_nan());
+        assert!(min.as_slice()[0].is_nan() && min.as_slice()[1].is_nan());
+        assert_eq!(max.as_slice()[2], 2.0);
+        assert_eq!(min.as_slice()[2], 0.0);
+    }
+
+    #[test]
+    fn test_fmax_fmin() {
+        let mut a = Tensor::from_slice([4], &[f32::NAN, 1.0, f32::NAN, 2.0]);
+        let b = Tensor::from_slice([4], &[1.0, f32::NAN, f32::NAN, 3.0]);
+
+        assert_eq!(a.fmin(&b).as_slice()[..2], [1.0, 1.0]);
+        assert_eq!(a.fmin(&b).as_slice()[3], 2.0);
+
+        a.fmax_inplace(&b);
+
+        assert_eq!(a.as_slice()[..2], [1.0, 1.0]);
+        assert!(a.as_slice()[2].is_nan());
+        assert_eq!(a.as_slice()[3], 3.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let mut tensor = Tensor::from_slice([2, 3], &[-5, -1, 0, 1, 5, 9]);
+
+        assert_eq!(tensor.clamp(-1, 1).as_slice(), &[-1, -1, 0, 1, 1, 1]);
+
+        tensor.clamp_inplace(0, 5);
+
+        assert_eq!(tensor.as_slice(), &[0, 0, 0, 1, 5, 5]);
+    }
+
+    #[test]
+    fn test_clamp_nan() {
+        let tensor = Tensor::from_slice([2], &[f64::NAN, 3.0]);
+
+        let result = tensor.clamp(0.0, 1.0);
+
+        assert!(result.as_slice()[0].is_nan());
+        assert_eq!(result.as_slice()[1], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid clamp bounds")]
+    fn test_clamp_invalid_bounds() {
+        let tensor = Tensor::new_set([2], 1.0);
+
+        let _ = tensor.clamp(1.0, f64::NAN);
+    }
+
+    #[test]
+    fn test_clamp_tensor_inplace() {
+        let mut tensor = Tensor::from_slice([2, 2], &[0.0, 2.0, 4.0, 6.0]);
+        let lo = Tensor::from_slice([2, 2], &[1.0, 1.0, 1.0, 1.0]);
+        let hi = Tensor::from_slice([2, 2], &[1.0, 3.0, 5.0, 5.0]);
+
+        tensor.clamp_tensor_inplace(&lo, &hi);
+
+        assert_eq!(tensor.as_slice(), &[1.0, 2.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_clamp_tensor_invalid_bounds_unchanged() {
+        let mut tensor = Tensor::from_slice([3], &[0, 2, 4]);
+        let lo = Tensor::from_slice([3], &[1, 1, 3]);
+        let hi = Tensor::from_slice([3], &[1, 1, 2]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tensor.clamp_tensor_inplace(&lo, &hi)
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(tensor.as_slice(), &[0, 2, 4]);
+    }
+}
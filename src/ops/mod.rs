@@ -1,6 +1,39 @@
 mod add;
+mod band;
+mod bitand;
+mod bitor;
+mod bitxor;
+mod checked;
+mod conv;
+mod cumulative;
+mod diagonal;
 mod div;
+mod error;
+mod extrema;
+mod fused;
+mod gemm;
+mod map;
+mod math;
+mod minmax;
 mod mul;
 mod neg;
+mod norm;
+mod not;
+mod pairwise;
+mod pool;
+mod product;
+mod rem;
+mod scalar;
+mod shl;
+mod shr;
 mod similarity;
+mod sort;
 mod sub;
+mod windows;
+
+// Public exports
+pub use conv::{ConvOptions, PaddingMode};
+pub use diagonal::Diagonal;
+pub use error::ArithmeticError;
+pub use pairwise::Metric;
+pub use windows::{Window, Windows};
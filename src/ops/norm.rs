@@ -0,0 +1,345 @@
+use crate::Tensor;
+use crate::assertions::{assert_reduced_rank, assert_valid_axis};
+use crate::mem::pointers::UnmanagedPointer;
+use crate::metadata::TensorMetadata;
+use crate::num::{Accumulator, Float, Summation};
+
+/// Epsilon used by `normalize` as the least divisor, so that zero vectors remain zero.
+const NORMALIZE_EPSILON: f64 = 1e-12;
+
+/// Vector norms.
+#[derive(Clone, Copy)]
+enum Norm {
+    L1,
+    L2,
+    Inf,
+    P(f64),
+}
+
+impl Norm {
+    /// Returns the norm of order `p`.
+    fn of_order(p: f64) -> Self {
+        assert!(p >= 1.0, "Invalid norm order");
+
+        if p == 1.0 {
+            Self::L1
+        } else if p == 2.0 {
+            Self::L2
+        } else if p == f64::INFINITY {
+            Self::Inf
+        } else {
+            Self::P(p)
+        }
+    }
+}
+
+/// Returns the norm of `n` values of `a` from offset `start`, with `step` distance between them.
+/// Sums are accumulated with the global summation strategy.
+#[inline(always)]
+unsafe fn norm_of<T>(
+    norm: Norm,
+    n: usize,
+    step: usize,
+    a: &UnmanagedPointer<T>,
+    start: usize,
+) -> f64
+where
+    T: Copy + Into<f64>,
+{
+    let value = |k: usize| -> f64 { unsafe { (*a.reference(start + k * step)).into() } };
+
+    if let Norm::Inf = norm {
+        let mut max: f64 = 0.0;
+        let mut k = 0;
+        while k < n {
+            let x = value(k).abs();
+            if x > max || x.is_nan() {
+                max = x;
+            }
+            if max.is_nan() {
+                return max;
+            }
+            k += 1;
+        }
+        return max;
+    }
+
+    let mut sum = Accumulator::new(Summation::global());
+    let mut k = 0;
+    while k < n {
+        let x = value(k).abs();
+        sum.add(match norm {
+            Norm::L1 => x,
+            Norm::L2 => x * x,
+            Norm::P(p) => x.powf(p),
+            Norm::Inf => unreachable!(),
+        });
+        k += 1;
+    }
+
+    match norm {
+        Norm::L1 => sum.sum(),
+        Norm::L2 => sum.sum().sqrt(),
+        Norm::P(p) => sum.sum().powf(p.recip()),
+        Norm::Inf => unreachable!(),
+    }
+}
+
+/// Generates the whole tensor and along-axis variants of a norm.
+macro_rules! norm_fns {
+    ($($name:ident, $axis:ident, $norm:expr, $what:literal;)*) => {
+        $(
+            #[doc = concat!("Returns the ", $what, " of the elements.")]
+            pub fn $name(&self) -> f64 {
+                self.norm($norm)
+            }
+
+            #[doc = concat!("Returns the ", $what, " of each lane along `axis`, as")]
+            #[doc = "`Tensor<f64, N>` with the dimension of `axis` removed."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`."]
+            pub fn $axis<const N: usize>(&self, axis: usize) -> Tensor<f64, N> {
+                self.norm_axis(axis, $norm)
+            }
+        )*
+    };
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns `norm` of the elements.
+    fn norm(&self, norm: Norm) -> f64 {
+        let len = self.metadata.size();
+        unsafe { norm_of(norm, len, 1, &self.data, 0) }
+    }
+
+    /// Returns `norm` of each lane along `axis`.
+    fn norm_axis<const N: usize>(&self, axis: usize, norm: Norm) -> Tensor<f64, N> {
+        assert_valid_axis::<R>(axis);
+        assert_reduced_rank::<R, N>();
+
+        let metadata = TensorMetadata::new(self.metadata.remove_axis(axis));
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+        let a = &self.data;
+
+        Tensor::make_with(metadata, |j| {
+            let (o, i) = (j / inner, j % inner);
+            unsafe { norm_of(norm, len, inner, a, o * len * inner + i) }
+        })
+    }
+
+    norm_fns! {
+        norm_l1, norm_l1_axis, Norm::L1, "L1 norm (sum of absolute values)";
+        norm_l2, norm_l2_axis, Norm::L2, "L2 (Euclidean) norm";
+        norm_inf, norm_inf_axis, Norm::Inf, "L-infinity norm (greatest absolute value)";
+    }
+
+    /// Returns the p-norm `(sum |x|^p)^(1/p)` of the elements.
+    ///
+    /// `p` of infinity gives the L-infinity norm.
+    ///
+    /// # Panics
+    /// This method will panic if `p` is less than `1` or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([3], &[3, -4, 0]);
+    ///
+    /// assert_eq!(tensor.norm_p(1.0), 7.0);
+    /// assert_eq!(tensor.norm_p(2.0), 5.0);
+    /// assert_eq!(tensor.norm_p(f64::INFINITY), 4.0);
+    /// ```
+    pub fn norm_p(&self, p: f64) -> f64 {
+        self.norm(Norm::of_order(p))
+    }
+
+    /// Returns the p-norm of each lane along `axis`, as `Tensor<f64, N>` with the dimension of
+    /// `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, if `N` is not `R - 1`, or if `p` is
+    /// less than `1` or NaN.
+    pub fn norm_p_axis<const N: usize>(&self, axis: usize, p: f64) -> Tensor<f64, N> {
+        self.norm_axis(axis, Norm::of_order(p))
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the Frobenius norm of the matrix, the square root of the sum of the squares of
+    /// the elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let matrix = Tensor::from_slice([2, 2], &[1, 2, 2, 4]);
+    ///
+    /// assert_eq!(matrix.norm_fro(), 5.0);
+    /// ```
+    pub fn norm_fro(&self) -> f64 {
+        self.norm(Norm::L2)
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Float + Into<f64>,
+{
+    /// Scales each lane along `axis` to unit L2 norm and returns the result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// Lanes are divided by the greater of their norm and `1e-12`, so zero vectors remain zero,
+    /// and lanes with a NaN norm become NaN.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[3.0, 4.0, 0.0, 0.0]);
+    ///
+    /// let result = tensor.normalize(1);
+    ///
+    /// assert_eq!(result.as_slice(), &[0.6, 0.8, 0.0, 0.0]);
+    /// ```
+    pub fn normalize(&self, axis: usize) -> Self {
+        let mut output = self.clone();
+        output.normalize_inplace(axis);
+        output
+    }
+
+    /// Scales in-place each lane along `axis` to unit L2 norm.
+    ///
+    /// Lanes are divided by the greater of their norm and `1e-12`, so zero vectors remain zero,
+    /// and lanes with a NaN norm become NaN.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`.
+    pub fn normalize_inplace(&mut self, axis: usize) {
+        assert_valid_axis::<R>(axis);
+
+        let (outer, len, inner) = self.metadata.split_at_axis(axis);
+
+        let mut o = 0;
+        while o < outer {
+            let mut i = 0;
+            while i < inner {
+                let start = o * len * inner + i;
+
+                unsafe {
+                    let norm = norm_of(Norm::L2, len, inner, &self.data, start);
+                    // `f64::max` would drop a NaN norm, which must propagate instead.
+                    let divisor = if norm.is_nan() || norm >= NORMALIZE_EPSILON {
+                        norm
+                    } else {
+                        NORMALIZE_EPSILON
+                    };
+
+                    // The division is in `f64`, where the norm of `f32` values cannot overflow.
+                    let mut k = 0;
+                    while k < len {
+                        let x = self.data.reference_mut(start + k * inner);
+                        let value: f64 = (*x).into();
+                        *x = T::from_f64(value / divisor);
+                        k += 1;
+                    }
+                }
+                i += 1;
+            }
+            o += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod norm_tests {
+    use super::*;
+
+    #[test]
+    fn test_norms() {
+        let tensor = Tensor::from_slice([2, 2], &[1.0, -2.0, 2.0, -4.0]);
+
+        assert_eq!(tensor.norm_l1(), 9.0);
+        assert_eq!(tensor.norm_l2(), 5.0);
+        assert_eq!(tensor.norm_inf(), 4.0);
+        assert_eq!(tensor.norm_fro(), 5.0);
+        assert!((tensor.norm_p(3.0) - 81f64.cbrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_norm_inf_nan() {
+        let tensor = Tensor::from_slice([3], &[1.0, f64::NAN, 5.0]);
+
+        assert!(tensor.norm_inf().is_nan());
+    }
+
+    #[test]
+    fn test_normalize_nan_lane() {
+        let tensor = Tensor::from_slice([2, 2], &[f64::NAN, 0.0, 1.0, 0.0]);
+
+        let result = tensor.normalize(0);
+
+        assert!(result.as_slice()[0].is_nan() && result.as_slice()[2].is_nan());
+        assert_eq!(result.as_slice()[1], 0.0);
+        assert_eq!(result.as_slice()[3], 0.0);
+    }
+
+    #[test]
+    fn test_normalize_f32_norm_beyond_range() {
+        let tensor = Tensor::from_slice([2], &[3e38f32, 3e38]);
+
+        let result = tensor.normalize(0);
+
+        assert_eq!(result.as_slice(), &[core::f32::consts::FRAC_1_SQRT_2; 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid norm order")]
+    fn test_norm_p_invalid_order() {
+        let tensor = Tensor::from_slice([2], &[1.0, 2.0]);
+
+        let _ = tensor.norm_p(0.5);
+    }
+
+    #[test]
+    fn test_norm_axis() {
+        let tensor = Tensor::from_slice([2, 3], &[3i32, 0, -1, 4, 0, 1]);
+
+        let l2: Tensor<f64, 1> = tensor.norm_l2_axis(0);
+        let l1: Tensor<f64, 1> = tensor.norm_l1_axis(1);
+        let inf: Tensor<f64, 1> = tensor.norm_inf_axis(1);
+        let p: Tensor<f64, 1> = tensor.norm_p_axis(0, 1.0);
+
+        assert_eq!(l2.as_slice(), &[5.0, 0.0, 2f64.sqrt()]);
+        assert_eq!(l1.as_slice(), &[4.0, 5.0]);
+        assert_eq!(inf.as_slice(), &[3.0, 4.0]);
+        assert_eq!(p.as_slice(), &[7.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_normalize_inplace() {
+        let mut tensor = Tensor::from_slice([2, 3], &[3.0f32, 0.0, 1.0, 4.0, 0.0, 0.0]);
+
+        tensor.normalize_inplace(0);
+
+        assert_eq!(tensor.as_slice(), &[0.6, 0.0, 1.0, 0.8, 0.0, 0.0]);
+
+        let norms: Tensor<f64, 1> = tensor.norm_l2_axis(0);
+
+        assert!((norms.as_slice()[0] - 1.0).abs() < 1e-6);
+        assert_eq!(norms.as_slice()[1], 0.0);
+    }
+}
@@ -0,0 +1,119 @@
+use core::hint::unreachable_unchecked;
+use core::ops::Not;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+
+/// Inverts the bits of `n` values of `a`, and writes result to `r`.
+#[inline(always)]
+unsafe fn not<T>(n: usize, a: *const T, r: *mut T)
+where
+    T: Copy + Not<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(!a_i);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Not for &Tensor<T, R>
+where
+    T: Copy + Not<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise logical negation (bitwise NOT for integers) of the tensor and
+    /// returns new `Tensor<T, R>` as a result of the negation without consuming `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 3], 0b1010u8);
+    ///
+    /// let result = !&tensor;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &0b1111_0101);
+    /// assert_eq!(result.get(&[1, 2]), &0b1111_0101);
+    /// ```
+    fn not(self) -> Self::Output {
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            not(len, a, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Not for &mut Tensor<T, R>
+where
+    T: Copy + Not<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place logical negation (bitwise NOT for integers) of each element in the
+    /// tensor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 2], true);
+    ///
+    /// !&mut tensor;
+    ///
+    /// assert_eq!(tensor.get(&[0, 0]), &false);
+    /// assert_eq!(tensor.get(&[1, 1]), &false);
+    /// ```
+    fn not(self) {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        unsafe { not(len, a, a) }
+    }
+}
+
+#[cfg(test)]
+mod not_tests {
+    use super::*;
+
+    #[test]
+    fn test_not_new() {
+        let tensor = Tensor::from_slice([2, 2], &[0i8, -1, 5, i8::MIN]);
+
+        let result = !&tensor;
+
+        assert_eq!(result.as_slice(), &[-1, 0, -6, i8::MAX]);
+    }
+
+    #[test]
+    fn test_not_mutate() {
+        let mut tensor = Tensor::from_slice([2, 2], &[true, false, false, true]);
+
+        !&mut tensor;
+
+        assert_eq!(tensor.as_slice(), &[false, true, true, false]);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::Tensor;
+use crate::assertions::assert_same_row_len;
+use crate::mem::pointers::UnmanagedPointer;
+use crate::metadata::TensorMetadata;
+use crate::num::{Accumulator, Summation};
+
+use super::gemm::{MatrixRef, gemm};
+use super::similarity::{
+    bray_curtis_of, canberra_of, chebyshev_of, cosine_of, dot_of, hamming_of, manhattan_of,
+    minkowski_of, squared_euclidean_of,
+};
+
+/// Metric between two vectors `x` and `y`, for pairwise computations.
+/// The following metrics are defined:
+/// - `Dot`: Dot product.
+/// - `Cosine`: Cosine similarity, `0.0` if either vector is zero.
+/// - `Euclidean`: Euclidean distance.
+/// - `SquaredEuclidean`: Squared Euclidean distance.
+/// - `Manhattan`: Sum of the absolute differences.
+/// - `Chebyshev`: Greatest absolute difference.
+/// - `Minkowski(p)`: `(sum |x - y|^p)^(1/p)`, with `p` greater than or equal to `1`.
+/// - `Canberra`: `sum |x - y| / (|x| + |y|)`, where pairs of zeros add nothing.
+/// - `BrayCurtis`: `sum |x - y| / sum |x + y|`, `0.0` if the denominator is `0`.
+/// - `Hamming`: Number of positions at which the values differ.
+///
+/// `Dot` and `Cosine` are computed with matrix products. The Euclidean distances are summed
+/// from the differences, as `|x|^2 + |y|^2 - 2 x.y` cancels for close vectors far from the
+/// origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    Dot,
+    Cosine,
+    Euclidean,
+    SquaredEuclidean,
+    Manhattan,
+    Chebyshev,
+    Minkowski(f64),
+    Canberra,
+    BrayCurtis,
+    Hamming,
+}
+
+/// Number of rows of each side of the blocks computed by the full matrix functions.
+const BLOCK: usize = 256;
+
+/// Writes to `out` the `count` rows from row `start` of `a`, with `k` values each, converted to
+/// `f64`.
+fn convert_rows<T>(
+    a: &UnmanagedPointer<T>,
+    start: usize,
+    count: usize,
+    k: usize,
+    out: &mut Vec<f64>,
+) where
+    T: Copy + Into<f64>,
+{
+    out.clear();
+    let mut i = start * k;
+    while i < (start + count) * k {
+        out.push(unsafe { (*a.reference(i)).into() });
+        i += 1;
+    }
+}
+
+/// Writes to `out` the squared L2 norms of the rows of `k` values of `a`, accumulated with the
+/// global summation strategy.
+fn squared_norms(a: &[f64], k: usize, out: &mut Vec<f64>) {
+    let mut sum = Accumulator::new(Summation::global());
+
+    out.clear();
+    out.extend(a.chunks_exact(k).map(|row| {
+        sum.reset();
+        row.iter().for_each(|x| sum.add(x * x));
+        sum.sum()
+    }));
+}
+
+/// Returns `metric` between the lanes `x` and `y`, with the kernels of the single-pair
+/// functions. Sums are accumulated with the global summation strategy.
+fn metric_of(metric: Metric, x: &[f64], y: &[f64]) -> f64 {
+    let pairs = x.iter().copied().zip(y.iter().copied());
+    let summation = Summation::global();
+
+    match metric {
+        Metric::Dot => dot_of(pairs, summation),
+        Metric::Cosine => cosine_of(pairs, summation),
+        Metric::Euclidean => squared_euclidean_of(pairs, summation).sqrt(),
+        Metric::SquaredEuclidean => squared_euclidean_of(pairs, summation),
+        Metric::Manhattan => manhattan_of(pairs, summation),
+        Metric::Chebyshev => chebyshev_of(pairs),
+        Metric::Minkowski(p) => minkowski_of(pairs, p, summation),
+        Metric::Canberra => canberra_of(pairs, summation),
+        Metric::BrayCurtis => bray_curtis_of(pairs, summation),
+        Metric::Hamming => hamming_of(pairs) as f64,
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Copy + Into<f64>,
+{
+    /// Computes `metric` between each row of `self` and each row of `other` in blocks of at most
+    /// `block_size x block_size` pairs, and calls `f` with the offsets of the first row of
+    /// `self` and of `other` in the block, and the block of values.
+    ///
+    /// Memory use is bounded by the block size, independently of the number of rows.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `self` and `other` have different lengths, if
+    /// `block_size` is `0`, or if the order of `Metric::Minkowski` is less than `1` or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{Metric, Tensor};
+    ///
+    /// let queries = Tensor::from_slice([3, 2], &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    /// let database = Tensor::from_slice([2, 2], &[2.0, 0.0, 0.0, 3.0]);
+    ///
+    /// let mut best = [0usize; 3];
+    /// queries.cdist_blocks(&database, Metric::Dot, 2, |row, col, block| {
+    ///     for i in 0..block.shape()[0] {
+    ///         for j in 0..block.shape()[1] {
+    ///             if block.get(&[i, j]) > &0.0 && col + j > best[row + i] {
+    ///                 best[row + i] = col + j;
+    ///             }
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(best, [0, 1, 1]);
+    /// ```
+    pub fn cdist_blocks<F>(&self, other: &Self, metric: Metric, block_size: usize, mut f: F)
+    where
+        F: FnMut(usize, usize, &Tensor<f64, 2>),
+    {
+        assert_same_row_len(self, other);
+        assert!(block_size > 0, "Block size must be greater than `0`");
+
+        if let Metric::Minkowski(p) = metric {
+            assert!(p >= 1.0, "Invalid norm order");
+        }
+
+        let [m, k] = self.metadata.dims();
+        let [n, _] = other.metadata.dims();
+
+        let matmul = matches!(metric, Metric::Dot | Metric::Cosine);
+
+        let mut x: Vec<f64> = Vec::with_capacity(block_size.min(m) * k);
+        let mut y: Vec<f64> = Vec::with_capacity(block_size.min(n) * k);
+        let mut x_norms: Vec<f64> = Vec::with_capacity(block_size.min(m));
+        let mut y_norms: Vec<f64> = Vec::with_capacity(block_size.min(n));
+
+        let mut i0 = 0;
+        while i0 < m {
+            let bm = block_size.min(m - i0);
+            convert_rows(&self.data, i0, bm, k, &mut x);
+            if matmul {
+                squared_norms(&x, k, &mut x_norms);
+            }
+
+            let mut j0 = 0;
+            while j0 < n {
+                let bn = block_size.min(n - j0);
+                convert_rows(&other.data, j0, bn, k, &mut y);
+
+                let block = if matmul {
+                    squared_norms(&y, k, &mut y_norms);
+
+                    let mut block = Tensor::new_set([bm, bn], 0.0);
+
+                    unsafe {
+                        gemm(
+                            bm,
+                            bn,
+                            k,
+                            MatrixRef::row_major(x.as_ptr(), k),
+                            MatrixRef::transposed(y.as_ptr(), k),
+                            block.data.as_ptr_mut(),
+                            false,
+                        );
+                    }
+
+                    let (x_norms, y_norms) = (&x_norms, &y_norms);
+                    block
+                        .as_slice_mut()
+                        .iter_mut()
+                        .enumerate()
+                        .for_each(|(p, v)| {
+                            let (x_n, y_n) = (x_norms[p / bn], y_norms[p % bn]);
+                            *v = match metric {
+                                Metric::Cosine if x_n == 0.0 || y_n == 0.0 => 0.0,
+                                Metric::Cosine => *v / (x_n.sqrt() * y_n.sqrt()),
+                                _ => *v,
+                            };
+                        });
+
+                    block
+                } else {
+                    let (x, y) = (&x, &y);
+                    Tensor::make_with(TensorMetadata::new([bm, bn]), |p| {
+                        let (i, j) = (p / bn, p % bn);
+                        metric_of(metric, &x[i * k..(i + 1) * k], &y[j * k..(j + 1) * k])
+                    })
+                };
+
+                f(i0, j0, &block);
+
+                j0 += bn;
+            }
+            i0 += bm;
+        }
+    }
+
+    /// Returns `metric` between each row of `self` and each row of `other`, as `Tensor<f64, 2>`
+    /// with one row per row of `self` and one column per row of `other`.
+    ///
+    /// The values are computed in blocks, see [`Tensor::cdist_blocks`] to consume them without
+    /// allocating the whole result.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `self` and `other` have different lengths, or if
+    /// the order of `Metric::Minkowski` is less than `1` or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{Metric, Tensor};
+    ///
+    /// let a = Tensor::from_slice([2, 2], &[0, 0, 1, 1]);
+    /// let b = Tensor::from_slice([3, 2], &[1, 0, 2, 2, 0, 0]);
+    ///
+    /// let distances = a.cdist(&b, Metric::Manhattan);
+    ///
+    /// assert_eq!(distances.shape(), &[2, 3]);
+    /// assert_eq!(distances.as_slice(), &[1.0, 4.0, 0.0, 1.0, 2.0, 2.0]);
+    /// ```
+    pub fn cdist(&self, other: &Self, metric: Metric) -> Tensor<f64, 2> {
+        let [m, _] = self.metadata.dims();
+        let [n, _] = other.metadata.dims();
+
+        let output = Tensor::new_set([m, n], 0.0);
+        let r = output.data.as_ptr_mut();
+
+        self.cdist_blocks(other, metric, BLOCK, |i0, j0, block| {
+            let [bm, bn] = block.metadata.dims();
+            let b = block.data.as_ptr();
+
+            let mut i = 0;
+            while i < bm {
+                unsafe {
+                    r.add((i0 + i) * n + j0)
+                        .copy_from_nonoverlapping(b.add(i * bn), bn)
+                };
+                i += 1;
+            }
+        });
+
+        output
+    }
+
+    /// Returns the dot product of each row of `self` with each row of `other`, computed with
+    /// matrix products.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `self` and `other` have different lengths.
+    pub fn pairwise_dot(&self, other: &Self) -> Tensor<f64, 2> {
+        self.cdist(other, Metric::Dot)
+    }
+
+    /// Returns the cosine similarity of each row of `self` with each row of `other`, `0.0` if
+    /// either row is zero.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `self` and `other` have different lengths.
+    pub fn pairwise_cosine(&self, other: &Self) -> Tensor<f64, 2> {
+        self.cdist(other, Metric::Cosine)
+    }
+
+    /// Returns the Euclidean distance between each row of `self` and each row of `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the rows of `self` and `other` have different lengths.
+    pub fn pairwise_euclidean(&self, other: &Self) -> Tensor<f64, 2> {
+        self.cdist(other, Metric::Euclidean)
+    }
+}
+
+#[cfg(test)]
+mod pairwise_tests {
+    use super::*;
+
+    /// Returns row `i` of `a` as tensor of rank 1.
+    fn row(a: &Tensor<f64, 2>, i: usize) -> Tensor<f64, 1> {
+        let [_, k] = a.metadata.dims();
+        Tensor::from_slice([k], &a.as_slice()[i * k..(i + 1) * k])
+    }
+
+    fn samples(m: usize, k: usize, seed: u64) -> Tensor<f64, 2> {
+        let mut state = seed;
+        let values: Vec<f64> = (0..m * k)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state % 1000) as f64 / 100.0 - 5.0
+            })
+            .collect();
+        Tensor::from_slice([m, k], &values)
+    }
+
+    #[test]
+    fn test_pairwise_matches_single_pair() {
+        let a = samples(5, 7, 1);
+        let b = samples(4, 7, 2);
+
+        let dot = a.pairwise_dot(&b);
+        let cosine = a.pairwise_cosine(&b);
+        let euclidean = a.pairwise_euclidean(&b);
+        let manhattan = a.cdist(&b, Metric::Manhattan);
+        let minkowski = a.cdist(&b, Metric::Minkowski(3.0));
+        let canberra = a.cdist(&b, Metric::Canberra);
+        let bray_curtis = a.cdist(&b, Metric::BrayCurtis);
+        let chebyshev = a.cdist(&b, Metric::Chebyshev);
+
+        for i in 0..5 {
+            for j in 0..4 {
+                let (x, y) = (row(&a, i), row(&b, j));
+                let close = |v: &f64, w: f64| (v - w).abs() < 1e-9;
+
+                assert!(close(dot.get(&[i, j]), x.dot_product(&y)));
+                assert!(close(cosine.get(&[i, j]), x.cosine_similarity(&y)));
+                assert!(close(euclidean.get(&[i, j]), x.euclidean_distance(&y)));
+                assert!(close(manhattan.get(&[i, j]), x.manhattan_distance(&y)));
+                assert!(close(minkowski.get(&[i, j]), x.minkowski_distance(&y, 3.0)));
+                assert!(close(canberra.get(&[i, j]), x.canberra_distance(&y)));
+                assert!(close(bray_curtis.get(&[i, j]), x.bray_curtis_distance(&y)));
+                assert!(close(chebyshev.get(&[i, j]), x.chebyshev_distance(&y)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pairwise_euclidean_close_large_rows() {
+        // The expansion `|x|^2 + |y|^2 - 2 x.y` cancels to `0.0` for these rows.
+        let a = Tensor::from_slice([1, 2], &[1e8, 1e8 + 1.0]);
+        let b = Tensor::from_slice([1, 2], &[1e8 + 1.0, 1e8]);
+
+        let euclidean = a.pairwise_euclidean(&b);
+        let squared = a.cdist(&b, Metric::SquaredEuclidean);
+
+        assert_eq!(euclidean.as_slice(), &[core::f64::consts::SQRT_2]);
+        assert_eq!(squared.as_slice(), &[2.0]);
+        assert_eq!(
+            euclidean.as_slice()[0],
+            row(&a, 0).euclidean_distance(&row(&b, 0))
+        );
+    }
+
+    #[test]
+    fn test_cdist_blocks_cover_all_pairs() {
+        let a = samples(7, 3, 3);
+        let b = samples(5, 3, 4);
+
+        let full = a.cdist(&b, Metric::SquaredEuclidean);
+        let mut seen = [[false; 5]; 7];
+
+        a.cdist_blocks(&b, Metric::SquaredEuclidean, 3, |i0, j0, block| {
+            let [bm, bn] = [block.shape()[0], block.shape()[1]];
+            assert!(bm <= 3 && bn <= 3);
+
+            for i in 0..bm {
+                for j in 0..bn {
+                    assert!(!seen[i0 + i][j0 + j]);
+                    seen[i0 + i][j0 + j] = true;
+                    assert_eq!(block.get(&[i, j]), full.get(&[i0 + i, j0 + j]));
+                }
+            }
+        });
+
+        assert!(seen.iter().flatten().all(|s| *s));
+    }
+
+    #[test]
+    fn test_cosine_zero_rows_and_hamming() {
+        let a = Tensor::from_slice([2, 3], &[0u8, 0, 0, 1, 2, 3]);
+        let b = Tensor::from_slice([2, 3], &[1u8, 2, 3, 1, 0, 3]);
+
+        let cosine = a.pairwise_cosine(&b);
+        let hamming = a.cdist(&b, Metric::Hamming);
+
+        assert_eq!(cosine.as_slice()[..2], [0.0, 0.0]);
+        assert!((cosine.get(&[1, 0]) - 1.0).abs() < 1e-12);
+        assert_eq!(hamming.as_slice(), &[3.0, 2.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must have the same length")]
+    fn test_cdist_length_mismatch() {
+        let a = Tensor::new_set([2, 3], 1.0);
+        let b = Tensor::new_set([2, 4], 1.0);
+
+        let _ = a.cdist(&b, Metric::Euclidean);
+    }
+}
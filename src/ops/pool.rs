@@ -0,0 +1,576 @@
+use crate::Tensor;
+use crate::num::{Accumulator, Float, Summation};
+
+use super::conv::{ConvOptions, gather_columns, window_output_dims, window_sources};
+
+/// Reduction of the values of a pooling window.
+#[derive(Clone, Copy)]
+enum Pooling<T> {
+    Max,
+    Average,
+    Power(T),
+}
+
+/// Pools the windows of the `N x C x ..` input over the `D` spatial dimensions.
+///
+/// Padded positions are ignored by the maximum and count as zeros otherwise. Sums are accumulated
+/// as `f64` with the global summation strategy.
+fn pool<T, const D: usize, const R: usize>(
+    input: &Tensor<T, R>,
+    window: [usize; D],
+    stride: [usize; D],
+    padding: [usize; D],
+    pooling: Pooling<T>,
+) -> Tensor<T, R>
+where
+    T: Float,
+{
+    for d in 0..D {
+        assert!(
+            2 * padding[d] <= window[d],
+            "Padding must be at most half the window size"
+        );
+    }
+    if let Pooling::Power(p) = pooling {
+        assert!(p > T::ZERO, "Invalid norm order");
+    }
+
+    let options = ConvOptions {
+        stride,
+        padding,
+        ..ConvOptions::default()
+    };
+
+    let dims = input.metadata.dims();
+    let spatial = window_output_dims(&dims[2..], &window, &options);
+    let sources = window_sources(&dims[2..], &window, &spatial, &options);
+
+    let mut output_dims = dims;
+    output_dims[2..].copy_from_slice(&spatial);
+
+    let input_size: usize = dims[2..].iter().product();
+    let window_size: usize = window.iter().product();
+    let output_size: usize = spatial.iter().product();
+    let mut sum = Accumulator::new(Summation::global());
+
+    let x = input.as_slice();
+    let mut output = Vec::with_capacity(dims[0] * dims[1] * output_size);
+
+    for channel in x.chunks_exact(input_size) {
+        for op in 0..output_size {
+            let values = (0..window_size).filter_map(|wp| sources[wp * output_size + op]);
+
+            let value = match pooling {
+                Pooling::Max => values.map(|i| channel[i]).fold(T::NEG_INFINITY, |max, v| {
+                    if v > max || v.is_nan() { v } else { max }
+                }),
+                Pooling::Average => {
+                    sum.reset();
+                    values.for_each(|i| sum.add(channel[i].to_f64()));
+                    T::from_f64(sum.sum() / window_size as f64)
+                }
+                Pooling::Power(p) => {
+                    sum.reset();
+                    values.for_each(|i| sum.add(channel[i].abs().powf(p).to_f64()));
+                    T::from_f64(sum.sum()).powf(p.recip())
+                }
+            };
+            output.push(value);
+        }
+    }
+
+    Tensor::from_boxed_slice(output_dims, output.into_boxed_slice())
+}
+
+/// Copies the values of the windows of the `N x C x ..` input into the columns of a new
+/// `N x (C * window size) x L` tensor, one column per window position.
+fn unfold<T, const D: usize, const R: usize>(
+    input: &Tensor<T, R>,
+    window: [usize; D],
+    options: ConvOptions<D>,
+) -> Tensor<T, 3>
+where
+    T: Float,
+{
+    assert!(options.groups == 1, "Invalid number of groups");
+
+    let dims = input.metadata.dims();
+    let spatial = window_output_dims(&dims[2..], &window, &options);
+    let sources = window_sources(&dims[2..], &window, &spatial, &options);
+
+    let input_size: usize = dims[2..].iter().product();
+    let window_size: usize = window.iter().product();
+    let output_size: usize = spatial.iter().product();
+    let rows = window_size * output_size;
+
+    let x = input.as_slice();
+    let mut output = vec![T::ZERO; dims[0] * dims[1] * rows];
+
+    for (channel, columns) in x
+        .chunks_exact(input_size)
+        .zip(output.chunks_exact_mut(rows))
+    {
+        gather_columns(channel, &sources, columns);
+    }
+
+    let output_dims = [dims[0], dims[1] * window_size, output_size];
+    Tensor::from_boxed_slice(output_dims, output.into_boxed_slice())
+}
+
+/// Sums the columns of the `N x (C * window size) x L` input into the positions of a new
+/// `N x C x ..` tensor of spatial dimensions `output` that each window value was read from.
+fn fold<T, const D: usize, const R: usize>(
+    input: &Tensor<T, 3>,
+    output: [usize; D],
+    window: [usize; D],
+    options: ConvOptions<D>,
+) -> Tensor<T, R>
+where
+    T: Float,
+{
+    assert!(options.groups == 1, "Invalid number of groups");
+
+    let [batch, rows, blocks] = input.metadata.dims();
+    let window_size: usize = window.iter().product();
+    assert!(
+        rows % window_size == 0,
+        "Rows must be a multiple of the window size"
+    );
+
+    let spatial = window_output_dims(&output, &window, &options);
+    let sources = window_sources(&output, &window, &spatial, &options);
+    assert!(
+        spatial.iter().product::<usize>() == blocks,
+        "Invalid number of blocks"
+    );
+
+    let channels = rows / window_size;
+    let output_size: usize = output.iter().product();
+
+    let x = input.as_slice();
+    let mut values = vec![T::ZERO; batch * channels * output_size];
+
+    for (columns, channel) in x
+        .chunks_exact(window_size * blocks)
+        .zip(values.chunks_exact_mut(output_size))
+    {
+        for (value, source) in columns.iter().zip(&sources) {
+            if let Some(i) = source {
+                channel[*i] += *value;
+            }
+        }
+    }
+
+    let mut output_dims = [0; R];
+    output_dims[0] = batch;
+    output_dims[1] = channels;
+    output_dims[2..].copy_from_slice(&output);
+
+    Tensor::from_boxed_slice(output_dims, values.into_boxed_slice())
+}
+
+impl<T> Tensor<T, 3>
+where
+    T: Float,
+{
+    /// Returns the maxima of the windows of size `window` of the `N x C x L` input, `stride`
+    /// apart, as new `N x C x L'` tensor. Padded positions are ignored and NaN values propagate.
+    ///
+    /// # Panics
+    /// This method will panic if a window size or stride is `0`, if a padding is more than half
+    /// the window size, or if the window is larger than the padded input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 5], &[1.0, 3.0, 2.0, 5.0, 4.0]);
+    ///
+    /// let result = signal.max_pool1d([2], [2], [1]);
+    ///
+    /// assert_eq!(result.as_slice(), &[1.0, 3.0, 5.0]);
+    /// ```
+    pub fn max_pool1d(&self, window: [usize; 1], stride: [usize; 1], padding: [usize; 1]) -> Self {
+        pool(self, window, stride, padding, Pooling::Max)
+    }
+
+    /// Returns the averages of the windows of size `window` of the `N x C x L` input, `stride`
+    /// apart, as new `N x C x L'` tensor. Padded positions count as zeros.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `max_pool1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 4], &[1.0, 3.0, 2.0, 6.0]);
+    ///
+    /// let result = signal.avg_pool1d([2], [2], [0]);
+    ///
+    /// assert_eq!(result.as_slice(), &[2.0, 4.0]);
+    /// ```
+    pub fn avg_pool1d(&self, window: [usize; 1], stride: [usize; 1], padding: [usize; 1]) -> Self {
+        pool(self, window, stride, padding, Pooling::Average)
+    }
+
+    /// Returns the `p`-norms `(sum |x|^p)^(1/p)` of the windows of size `window` of the
+    /// `N x C x L` input, `stride` apart, as new `N x C x L'` tensor. Padded positions count as
+    /// zeros.
+    ///
+    /// # Panics
+    /// This method will panic if `p` is not positive, and under the same conditions as
+    /// `max_pool1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 4], &[3.0, -4.0, 0.0, 2.0]);
+    ///
+    /// let result = signal.lp_pool1d(2.0, [2], [2], [0]);
+    ///
+    /// assert_eq!(result.as_slice(), &[5.0, 2.0]);
+    /// ```
+    pub fn lp_pool1d(
+        &self,
+        p: T,
+        window: [usize; 1],
+        stride: [usize; 1],
+        padding: [usize; 1],
+    ) -> Self {
+        pool(self, window, stride, padding, Pooling::Power(p))
+    }
+
+    /// Copies the windows of size `window` of the `N x C x L` input into the columns of a new
+    /// `N x (C * K) x L'` tensor (im2col), the rows being ordered by channel and then by window
+    /// position.
+    ///
+    /// # Panics
+    /// This method will panic if `options.groups` is not `1`, and under the same conditions as
+    /// `conv1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let signal = Tensor::from_slice([1, 1, 4], &[1.0, 2.0, 3.0, 4.0]);
+    ///
+    /// let columns = signal.unfold1d([2], ConvOptions::default());
+    ///
+    /// assert_eq!(columns.shape(), &[1, 2, 3]);
+    /// assert_eq!(columns.as_slice(), &[1.0, 2.0, 3.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn unfold1d(&self, window: [usize; 1], options: ConvOptions<1>) -> Self {
+        unfold(self, window, options)
+    }
+
+    /// Sums the columns of the `N x (C * K) x L'` input into the positions of a new `N x C x L`
+    /// tensor of length `output` that each value was read from by `unfold1d` (col2im), adding
+    /// the values of padded positions to the positions they reflect or replicate.
+    ///
+    /// # Panics
+    /// This method will panic if the rows are not a multiple of the window size, if the number
+    /// of columns doesn't match the number of windows, and under the same conditions as
+    /// `unfold1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{ConvOptions, Tensor};
+    ///
+    /// let columns = Tensor::new_set([1, 2, 3], 1.0);
+    ///
+    /// let result = columns.fold1d([4], [2], ConvOptions::default());
+    ///
+    /// // Number of windows containing each position.
+    /// assert_eq!(result.as_slice(), &[1.0, 2.0, 2.0, 1.0]);
+    /// ```
+    pub fn fold1d(&self, output: [usize; 1], window: [usize; 1], options: ConvOptions<1>) -> Self {
+        fold(self, output, window, options)
+    }
+
+    /// Sums the columns of the `N x (C * KH * KW) x L` input into a new `N x C x H x W` tensor of
+    /// spatial dimensions `output`, the inverse operation of `unfold2d` (col2im).
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `fold1d`.
+    pub fn fold2d(
+        &self,
+        output: [usize; 2],
+        window: [usize; 2],
+        options: ConvOptions<2>,
+    ) -> Tensor<T, 4> {
+        fold(self, output, window, options)
+    }
+
+    /// Sums the columns of the `N x (C * KD * KH * KW) x L` input into a new
+    /// `N x C x D x H x W` tensor of spatial dimensions `output`, the inverse operation of
+    /// `unfold3d` (col2im).
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `fold1d`.
+    pub fn fold3d(
+        &self,
+        output: [usize; 3],
+        window: [usize; 3],
+        options: ConvOptions<3>,
+    ) -> Tensor<T, 5> {
+        fold(self, output, window, options)
+    }
+}
+
+impl<T> Tensor<T, 4>
+where
+    T: Float,
+{
+    /// Returns the maxima of the windows of size `window` of the `N x C x H x W` input,
+    /// `stride` apart, as new `N x C x H' x W'` tensor.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `max_pool1d`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let image = Tensor::from_slice([1, 1, 2, 4], &[1.0, 5.0, 2.0, 0.0, 3.0, 4.0, 8.0, 6.0]);
+    ///
+    /// let result = image.max_pool2d([2, 2], [2, 2], [0, 0]);
+    ///
+    /// assert_eq!(result.as_slice(), &[5.0, 8.0]);
+    /// ```
+    pub fn max_pool2d(&self, window: [usize; 2], stride: [usize; 2], padding: [usize; 2]) -> Self {
+        pool(self, window, stride, padding, Pooling::Max)
+    }
+
+    /// Returns the averages of the windows of size `window` of the `N x C x H x W` input,
+    /// `stride` apart, as new `N x C x H' x W'` tensor. Padded positions count as zeros.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `max_pool1d`.
+    pub fn avg_pool2d(&self, window: [usize; 2], stride: [usize; 2], padding: [usize; 2]) -> Self {
+        pool(self, window, stride, padding, Pooling::Average)
+    }
+
+    /// Returns the `p`-norms of the windows of size `window` of the `N x C x H x W` input,
+    /// `stride` apart, as new `N x C x H' x W'` tensor. Padded positions count as zeros.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `lp_pool1d`.
+    pub fn lp_pool2d(
+        &self,
+        p: T,
+        window: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+    ) -> Self {
+        pool(self, window, stride, padding, Pooling::Power(p))
+    }
+
+    /// Copies the windows of size `window` of the `N x C x H x W` input into the columns of a
+    /// new `N x (C * KH * KW) x L` tensor (im2col).
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `unfold1d`.
+    pub fn unfold2d(&self, window: [usize; 2], options: ConvOptions<2>) -> Tensor<T, 3> {
+        unfold(self, window, options)
+    }
+}
+
+impl<T> Tensor<T, 5>
+where
+    T: Float,
+{
+    /// Returns the maxima of the windows of size `window` of the `N x C x D x H x W` input,
+    /// `stride` apart, as new `N x C x D' x H' x W'` tensor.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `max_pool1d`.
+    pub fn max_pool3d(&self, window: [usize; 3], stride: [usize; 3], padding: [usize; 3]) -> Self {
+        pool(self, window, stride, padding, Pooling::Max)
+    }
+
+    /// Returns the averages of the windows of size `window` of the `N x C x D x H x W` input,
+    /// `stride` apart, as new `N x C x D' x H' x W'` tensor. Padded positions count as zeros.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `max_pool1d`.
+    pub fn avg_pool3d(&self, window: [usize; 3], stride: [usize; 3], padding: [usize; 3]) -> Self {
+        pool(self, window, stride, padding, Pooling::Average)
+    }
+
+    /// Returns the `p`-norms of the windows of size `window` of the `N x C x D x H x W` input,
+    /// `stride` apart, as new `N x C x D' x H' x W'` tensor. Padded positions count as zeros.
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `lp_pool1d`.
+    pub fn lp_pool3d(
+        &self,
+        p: T,
+        window: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+    ) -> Self {
+        pool(self, window, stride, padding, Pooling::Power(p))
+    }
+
+    /// Copies the windows of size `window` of the `N x C x D x H x W` input into the columns of
+    /// a new `N x (C * KD * KH * KW) x L` tensor (im2col).
+    ///
+    /// # Panics
+    /// This method will panic under the same conditions as `unfold1d`.
+    pub fn unfold3d(&self, window: [usize; 3], options: ConvOptions<3>) -> Tensor<T, 3> {
+        unfold(self, window, options)
+    }
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+    use crate::PaddingMode;
+    use crate::metadata::TensorMetadata;
+
+    #[test]
+    fn test_pools_match_windows() {
+        let input = Tensor::make_with(TensorMetadata::new([2, 3, 5, 6]), |p| {
+            ((p * 7919) % 29) as f64 - 14.0
+        });
+
+        let max = input.max_pool2d([2, 3], [1, 2], [0, 0]);
+        let avg = input.avg_pool2d([2, 3], [1, 2], [0, 0]);
+        let lp = input.lp_pool2d(3.0, [2, 3], [1, 2], [0, 0]);
+
+        assert_eq!(max.shape(), &[2, 3, 4, 2]);
+
+        let windows = input.windows([1, 1, 2, 3], [1, 1, 1, 2]);
+        for (n, window) in windows.enumerate() {
+            let values: Vec<f64> = window.iter().copied().collect();
+
+            let expected_max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let expected_avg = values.iter().sum::<f64>() / 6.0;
+            let expected_lp = values.iter().map(|v| v.abs().powi(3)).sum::<f64>().cbrt();
+
+            assert_eq!(max.as_slice()[n], expected_max);
+            assert!((avg.as_slice()[n] - expected_avg).abs() < 1e-12);
+            assert!((lp.as_slice()[n] - expected_lp).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_pool_padding() {
+        let input = Tensor::from_slice([1, 1, 1, 2, 2], &[-1.0f32, -2.0, -3.0, -4.0]);
+
+        // Padded positions are ignored by the maximum and count as zeros in the average.
+        let max = input.max_pool3d([1, 2, 2], [1, 2, 2], [0, 1, 1]);
+        let avg = input.avg_pool3d([1, 2, 2], [1, 2, 2], [0, 1, 1]);
+
+        assert_eq!(max.as_slice(), &[-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!(avg.as_slice(), &[-0.25, -0.5, -0.75, -1.0]);
+
+        let nan = Tensor::from_slice([1, 1, 3], &[1.0, f64::NAN, 2.0]);
+        assert!(nan.max_pool1d([2], [1], [0]).iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_avg_pool_f32_accumulated_as_f64() {
+        // The sum in `f32` loses both ones to the large values.
+        let input = Tensor::from_slice([1, 1, 4], &[1.0f32, 1e8, 1.0, -1e8]);
+
+        assert_eq!(input.avg_pool1d([4], [1], [0]).as_slice(), &[0.5]);
+    }
+
+    #[test]
+    fn test_unfold_matches_conv() {
+        let input = Tensor::make_with(TensorMetadata::new([2, 3, 4, 5]), |p| (p % 11) as f64);
+        let kernel = Tensor::make_with(TensorMetadata::new([4, 3, 2, 3]), |p| p as f64 - 30.0);
+
+        let options = ConvOptions {
+            stride: [1, 2],
+            padding: [1, 1],
+            padding_mode: PaddingMode::Replicate,
+            dilation: [2, 1],
+            groups: 1,
+        };
+
+        let columns = input.unfold2d([2, 3], options);
+        let expected = input.correlate2d(&kernel, options);
+
+        let [batch, rows, blocks] = columns.metadata.dims();
+        assert_eq!([batch, rows, blocks], [2, 18, 12]);
+
+        // The correlation is the product of the kernel matrix with the columns.
+        for n in 0..batch {
+            for f in 0..4 {
+                for l in 0..blocks {
+                    let sum: f64 = (0..rows)
+                        .map(|r| kernel.as_slice()[f * rows + r] * columns.get(&[n, r, l]))
+                        .sum();
+                    assert_eq!(sum, expected.as_slice()[(n * 4 + f) * blocks + l]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold_is_adjoint_of_unfold() {
+        // <unfold(x), y> == <x, fold(y)> for every padding mode.
+        let x = Tensor::make_with(TensorMetadata::new([1, 2, 3, 4, 3]), |p| (p % 5) as f64);
+
+        for padding_mode in [
+            PaddingMode::Zero,
+            PaddingMode::Reflect,
+            PaddingMode::Replicate,
+        ] {
+            let options = ConvOptions {
+                stride: [1, 2, 1],
+                padding: [1, 1, 2],
+                padding_mode,
+                dilation: [1, 1, 2],
+                groups: 1,
+            };
+
+            let columns = x.unfold3d([2, 2, 2], options);
+            let y = Tensor::make_with(columns.metadata, |p| ((p * 31) % 7) as f64 - 3.0);
+
+            let folded = y.fold3d([3, 4, 3], [2, 2, 2], options);
+
+            let left: f64 = columns.iter().zip(y.iter()).map(|(a, b)| a * b).sum();
+            let right: f64 = x.iter().zip(folded.iter()).map(|(a, b)| a * b).sum();
+
+            assert_eq!(folded.shape(), x.shape());
+            assert_eq!(left, right);
+        }
+    }
+
+    #[test]
+    fn test_fold_2d() {
+        let columns = Tensor::new_set([1, 4, 4], 1.0);
+
+        let result = columns.fold2d([3, 3], [2, 2], ConvOptions::default());
+
+        assert_eq!(
+            result.as_slice(),
+            &[1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of blocks")]
+    fn test_fold_invalid_blocks() {
+        let columns = Tensor::new_set([1, 2, 2], 1.0);
+
+        columns.fold1d([4], [2], ConvOptions::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "Padding must be at most half the window size")]
+    fn test_pool_padding_too_large() {
+        Tensor::new_set([1, 1, 4], 1.0).avg_pool1d([2], [1], [2]);
+    }
+}
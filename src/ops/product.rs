@@ -0,0 +1,295 @@
+use core::ops::{AddAssign, Mul};
+
+use crate::Tensor;
+use crate::assertions::{assert_product_rank, assert_valid_axis};
+use crate::metadata::TensorMetadata;
+
+/// Returns the values of `a` in row-major order of the tensor whose axis `k` is axis `order[k]`
+/// of `a`.
+fn permuted<T, const R: usize>(a: &Tensor<T, R>, order: &[usize]) -> Vec<T>
+where
+    T: Copy,
+{
+    let dims = a.metadata.dims();
+    let strides = a.metadata.strides();
+    let values = a.as_slice();
+
+    let size = a.metadata.size();
+    let mut output = Vec::with_capacity(size);
+    let mut index = [0; R];
+    let mut offset = 0;
+
+    let mut n = 0;
+    while n < size {
+        output.push(values[offset]);
+
+        // Increments the index from the last axis, carrying to the previous axes.
+        let mut k = R;
+        while k > 0 {
+            k -= 1;
+            let axis = order[k];
+
+            index[k] += 1;
+            offset += strides[axis];
+            if index[k] < dims[axis] {
+                break;
+            }
+            offset -= strides[axis] * dims[axis];
+            index[k] = 0;
+        }
+        n += 1;
+    }
+
+    output
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Returns the outer product of `self` and `other` as `Tensor<T, M>`, whose dimensions are
+    /// the dimensions of `self` followed by the dimensions of `other`, and whose value at index
+    /// `[i.., j..]` is `self[i..] * other[j..]`.
+    ///
+    /// # Panics
+    /// This method will panic if `M` is not `R + N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([2], &[1, 2]);
+    /// let b = Tensor::from_slice([3], &[1, 10, 100]);
+    ///
+    /// let product: Tensor<i32, 2> = a.outer(&b);
+    ///
+    /// assert_eq!(product.shape(), &[2, 3]);
+    /// assert_eq!(product.as_slice(), &[1, 10, 100, 2, 20, 200]);
+    /// ```
+    pub fn outer<const N: usize, const M: usize>(&self, other: &Tensor<T, N>) -> Tensor<T, M> {
+        assert_product_rank::<R, N, 0, M>();
+
+        let mut dims = [0; M];
+        dims[..R].copy_from_slice(self.metadata.shape());
+        dims[R..].copy_from_slice(other.metadata.shape());
+
+        let (a, b) = (self.as_slice(), other.as_slice());
+        let n = b.len();
+
+        Tensor::make_with(TensorMetadata::new(dims), |p| a[p / n] * b[p % n])
+    }
+
+    /// Contracts the axes `axes[k].0` of `self` with the axes `axes[k].1` of `other` and returns
+    /// the result as `Tensor<T, M>`, whose dimensions are the remaining dimensions of `self`
+    /// followed by the remaining dimensions of `other`.
+    ///
+    /// The value at index `[i.., j..]` is the sum over the contracted indices `k..` of
+    /// `self[i.., k..] * other[k.., j..]`, accumulated naively in `T`. Without axes, the result
+    /// is the outer product, and the matrix product contracts the axes `(1, 0)`.
+    ///
+    /// # Panics
+    /// This method will panic if `M` is not `R + N - 2 * K`, if an axis is out of bounds or is
+    /// contracted more than once, or if the dimensions of contracted axes differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([2, 3], &[1, 2, 3, 4, 5, 6]);
+    /// let b = Tensor::from_slice([3, 2], &[1, 0, 0, 1, 1, 1]);
+    ///
+    /// // Matrix product.
+    /// let product: Tensor<i32, 2> = a.tensordot(&b, [(1, 0)]);
+    ///
+    /// assert_eq!(product.as_slice(), &[4, 5, 10, 11]);
+    ///
+    /// // Sum of the products of the transposed values.
+    /// let total: Tensor<i32, 0> = a.tensordot(&b, [(0, 1), (1, 0)]);
+    ///
+    /// assert_eq!(total.as_slice(), &[15]);
+    /// ```
+    pub fn tensordot<const N: usize, const K: usize, const M: usize>(
+        &self,
+        other: &Tensor<T, N>,
+        axes: [(usize, usize); K],
+    ) -> Tensor<T, M>
+    where
+        T: Default + AddAssign,
+    {
+        assert_product_rank::<R, N, K, M>();
+
+        let (a_dims, b_dims) = (self.metadata.dims(), other.metadata.dims());
+        let mut a_contracted = [false; R];
+        let mut b_contracted = [false; N];
+
+        for (i, j) in axes {
+            assert_valid_axis::<R>(i);
+            assert_valid_axis::<N>(j);
+            assert!(
+                !a_contracted[i] && !b_contracted[j],
+                "Axis contracted more than once"
+            );
+            assert!(a_dims[i] == b_dims[j], "Contracted dimensions must match");
+
+            a_contracted[i] = true;
+            b_contracted[j] = true;
+        }
+
+        // `self` as `p x c` matrix and `other` as `c x q` matrix.
+        let free_a = (0..R).filter(|&i| !a_contracted[i]);
+        let free_b = (0..N).filter(|&j| !b_contracted[j]);
+
+        let a_order: Vec<usize> = free_a.clone().chain(axes.iter().map(|a| a.0)).collect();
+        let b_order: Vec<usize> = axes.iter().map(|a| a.1).chain(free_b.clone()).collect();
+
+        let a = permuted(self, &a_order);
+        let b = permuted(other, &b_order);
+
+        let mut dims = [0; M];
+        for (d, axis) in dims.iter_mut().zip(free_a.map(|i| a_dims[i])) {
+            *d = axis;
+        }
+        for (d, axis) in dims[R - K..].iter_mut().zip(free_b.map(|j| b_dims[j])) {
+            *d = axis;
+        }
+
+        let p: usize = dims[..R - K].iter().product();
+        let q: usize = dims[R - K..].iter().product();
+        let c = a.len() / p;
+
+        let mut output = vec![T::default(); p * q];
+
+        let mut i = 0;
+        while i < p {
+            let mut k = 0;
+            while k < c {
+                let a_ik = a[i * c + k];
+                let mut j = 0;
+                while j < q {
+                    output[i * q + j] += a_ik * b[k * q + j];
+                    j += 1;
+                }
+                k += 1;
+            }
+            i += 1;
+        }
+
+        Tensor::from_boxed_slice(dims, output.into_boxed_slice())
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Returns the Kronecker product of `self` and `other`, the block matrix whose block `(i, j)`
+    /// is `other` multiplied by `self[i, j]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([1, 2], &[1, 2]);
+    /// let b = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+    ///
+    /// let product = a.kron(&b);
+    ///
+    /// assert_eq!(product.shape(), &[2, 4]);
+    /// assert_eq!(product.as_slice(), &[1, 2, 2, 4, 3, 4, 6, 8]);
+    /// ```
+    pub fn kron(&self, other: &Self) -> Self {
+        let [m, n] = self.metadata.dims();
+        let [p, q] = other.metadata.dims();
+        let (a, b) = (self.as_slice(), other.as_slice());
+        let cols = n * q;
+
+        Tensor::make_with(TensorMetadata::new([m * p, cols]), |o| {
+            let (row, col) = (o / cols, o % cols);
+            a[row / p * n + col / q] * b[row % p * q + col % q]
+        })
+    }
+}
+
+#[cfg(test)]
+mod product_tests {
+    use super::*;
+
+    #[test]
+    fn test_outer_ranks() {
+        let a = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let b = Tensor::from_slice([2], &[1, -1]);
+
+        let product: Tensor<i32, 3> = a.outer(&b);
+
+        assert_eq!(product.shape(), &[2, 2, 2]);
+        assert_eq!(product.get(&[1, 0, 1]), &-3);
+        assert_eq!(product.get(&[0, 1, 0]), &2);
+    }
+
+    #[test]
+    fn test_tensordot_matches_loops() {
+        // Contracts axis 0 of `a` [2, 3, 4] with axis 2 of `b` [4, 5, 2], and axis 2 of `a` with
+        // axis 0 of `b`.
+        let a = Tensor::make_with(TensorMetadata::new([2, 3, 4]), |p| p as i64 - 7);
+        let b = Tensor::make_with(TensorMetadata::new([4, 5, 2]), |p| (p % 7) as i64 - 3);
+
+        let result: Tensor<i64, 2> = a.tensordot(&b, [(0, 2), (2, 0)]);
+
+        assert_eq!(result.shape(), &[3, 5]);
+
+        for i in 0..3 {
+            for j in 0..5 {
+                let mut sum = 0;
+                for x in 0..2 {
+                    for y in 0..4 {
+                        sum += a.get(&[x, i, y]) * b.get(&[y, j, x]);
+                    }
+                }
+                assert_eq!(result.get(&[i, j]), &sum);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tensordot_without_axes_is_outer() {
+        let a = Tensor::from_slice([2], &[1.0, 2.0]);
+        let b = Tensor::from_slice([2], &[3.0, 4.0]);
+
+        let dot: Tensor<f64, 2> = a.tensordot(&b, []);
+        let outer: Tensor<f64, 2> = a.outer(&b);
+
+        assert_eq!(dot, outer);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contracted dimensions must match")]
+    fn test_tensordot_dimension_mismatch() {
+        let a = Tensor::new_set([2, 3], 1);
+        let b = Tensor::new_set([2, 3], 1);
+
+        let _: Tensor<i32, 2> = a.tensordot(&b, [(1, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis contracted more than once")]
+    fn test_tensordot_repeated_axis() {
+        let a = Tensor::new_set([2, 2], 1);
+        let b = Tensor::new_set([2, 2], 1);
+
+        let _: Tensor<i32, 0> = a.tensordot(&b, [(0, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn test_kron_identity() {
+        let identity = Tensor::from_slice([2, 2], &[1, 0, 0, 1]);
+        let b = Tensor::from_slice([1, 2], &[5, 6]);
+
+        let product = identity.kron(&b);
+
+        assert_eq!(product.shape(), &[2, 4]);
+        assert_eq!(product.as_slice(), &[5, 6, 0, 0, 0, 0, 5, 6]);
+    }
+}
@@ -0,0 +1,381 @@
+use core::hint::unreachable_unchecked;
+use core::ops::Rem;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::{assert_non_zero_divisors, assert_same_shape};
+use crate::num::Integer;
+
+/// Computes the remainders of `n` values of `a` divided by `b` and writes result to `r`.
+#[inline(always)]
+unsafe fn rem<T>(n: usize, a: *const T, b: *const T, r: *mut T)
+where
+    T: Copy + Rem<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(a_i % b_i);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Rem<Self> for &Tensor<T, R>
+where
+    T: Copy + Rem<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise remainder between `self` and `other` tensor and returns new
+    /// `Tensor<T, R>` as a result of the remainder without consuming `self` or `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// It will panic also if any value of `other` is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor1 = Tensor::new_set([2, 3], 7);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// let result = &tensor1 % &tensor2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &1);
+    /// assert_eq!(result.get(&[1, 2]), &1);
+    /// ```
+    fn rem(self, other: Self) -> Tensor<T, R> {
+        assert_same_shape(self, other);
+
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+
+        let a = self.data.as_ptr();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            rem(len, a, b, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Rem<&Tensor<T, R>> for &mut Tensor<T, R>
+where
+    T: Copy + Rem<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise remainder between `self` and another tensor.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// It will panic also if any value of `other` is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor1 = Tensor::new_set([2, 3], 7);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// &mut tensor1 % &tensor2;
+    ///
+    /// assert_eq!(tensor1.get(&[0, 0]), &1);
+    /// assert_eq!(tensor1.get(&[1, 2]), &1);
+    /// ```
+    fn rem(self, other: &Tensor<T, R>) {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            rem(len, a, b, a);
+        }
+    }
+}
+
+/// Computes the remainders of `n` values of `a` divided by `v`, and writes result to `r`.
+#[inline(always)]
+unsafe fn rem_value<T>(n: usize, a: *const T, v: T, r: *mut T)
+where
+    T: Copy + Rem<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(a_i % v);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Rem<T> for &Tensor<T, R>
+where
+    T: Copy + Rem<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise remainder between `self` and `value` and returns result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 3], 7);
+    ///
+    /// let result = &tensor % 4;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &3);
+    /// assert_eq!(result.get(&[1, 2]), &3);
+    /// ```
+    fn rem(self, value: T) -> Tensor<T, R> {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            rem_value(len, a, value, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Rem<T> for &mut Tensor<T, R>
+where
+    T: Copy + Rem<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise remainder between `self` and `value`.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 3], 7);
+    ///
+    /// &mut tensor % 4;
+    ///
+    /// assert_eq!(tensor.get(&[0, 0]), &3);
+    /// assert_eq!(tensor.get(&[1, 2]), &3);
+    /// ```
+    fn rem(self, value: T) {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+
+        unsafe {
+            rem_value(len, a, value, a);
+        }
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Integer,
+{
+    /// Computes the least non-negative remainder of `self` divided by `other` element-wise and
+    /// returns new `Tensor<T, R>` as a result.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match, if any value
+    /// of `other` is `0`, or if `MIN % -1` of signed types overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([4], &[7, -7, 7, -7]);
+    /// let b = Tensor::from_slice([4], &[4, 4, -4, -4]);
+    ///
+    /// assert_eq!(a.rem_euclid(&b).as_slice(), &[3, 1, 3, 1]);
+    /// ```
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        assert_same_shape(self, other);
+        assert_non_zero_divisors(other);
+
+        let a = &self.data;
+        let b = &other.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).rem_euclid(*b.reference(i))
+        })
+    }
+
+    /// Computes the least non-negative remainder of `self` divided by `value` element-wise and
+    /// returns new `Tensor<T, R>` as a result.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0`, or if `MIN % -1` of signed types overflows.
+    pub fn rem_euclid_value(&self, value: T) -> Self {
+        assert!(value != T::ZERO, "Division by zero");
+
+        let a = &self.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).rem_euclid(value)
+        })
+    }
+
+    /// Computes the Euclidean quotient of `self` divided by `other` element-wise and returns
+    /// new `Tensor<T, R>` as a result.
+    ///
+    /// The quotient `q` satisfies `self = other * q + r` with `0 <= r < |other|`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match, if any value
+    /// of `other` is `0`, or if `MIN / -1` of signed types overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let a = Tensor::from_slice([4], &[7, -7, 7, -7]);
+    /// let b = Tensor::from_slice([4], &[4, 4, -4, -4]);
+    ///
+    /// assert_eq!(a.div_euclid(&b).as_slice(), &[1, -2, -1, 2]);
+    /// ```
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        assert_same_shape(self, other);
+        assert_non_zero_divisors(other);
+
+        let a = &self.data;
+        let b = &other.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).div_euclid(*b.reference(i))
+        })
+    }
+
+    /// Computes the Euclidean quotient of `self` divided by `value` element-wise and returns
+    /// new `Tensor<T, R>` as a result.
+    ///
+    /// # Panics
+    /// This method will panic if `value` is `0`, or if `MIN / -1` of signed types overflows.
+    pub fn div_euclid_value(&self, value: T) -> Self {
+        assert!(value != T::ZERO, "Division by zero");
+
+        let a = &self.data;
+
+        Tensor::make_with(self.metadata, |i| unsafe {
+            (*a.reference(i)).div_euclid(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod rem_tests {
+    use super::*;
+
+    #[test]
+    fn test_rem_new() {
+        let tensor1 = Tensor::from_slice([2, 2], &[7, -7, 9, 10]);
+        let tensor2 = Tensor::from_slice([2, 2], &[3, 3, 4, 5]);
+
+        let result = &tensor1 % &tensor2;
+
+        assert_eq!(result.as_slice(), &[1, -1, 1, 0]);
+    }
+
+    #[test]
+    fn test_rem_mutate() {
+        let mut tensor1 = Tensor::from_slice([2, 2], &[7, -7, 9, 10]);
+        let tensor2 = Tensor::from_slice([2, 2], &[3, 3, 4, 5]);
+
+        &mut tensor1 % &tensor2;
+
+        assert_eq!(tensor1.as_slice(), &[1, -1, 1, 0]);
+    }
+
+    #[test]
+    fn test_rem_value() {
+        let tensor = Tensor::from_slice([2, 2], &[7, -7, 9, 10]);
+
+        let result = &tensor % 4;
+
+        assert_eq!(result.as_slice(), &[3, -3, 1, 2]);
+    }
+
+    #[test]
+    fn test_rem_value_mut() {
+        let mut tensor = Tensor::from_slice([2, 2], &[7, -7, 9, 10]);
+
+        &mut tensor % 4;
+
+        assert_eq!(tensor.as_slice(), &[3, -3, 1, 2]);
+    }
+
+    #[test]
+    fn test_rem_euclid() {
+        let a = Tensor::from_slice([2, 2], &[7, -7, 7, -7]);
+        let b = Tensor::from_slice([2, 2], &[3, 3, -3, -3]);
+
+        assert_eq!(a.rem_euclid(&b).as_slice(), &[1, 2, 1, 2]);
+        assert_eq!(a.rem_euclid_value(5).as_slice(), &[2, 3, 2, 3]);
+    }
+
+    #[test]
+    fn test_div_euclid() {
+        let a = Tensor::from_slice([2, 2], &[7, -7, 7, -7]);
+        let b = Tensor::from_slice([2, 2], &[3, 3, -3, -3]);
+
+        assert_eq!(a.div_euclid(&b).as_slice(), &[2, -3, -2, 3]);
+        assert_eq!(a.div_euclid_value(5).as_slice(), &[1, -2, 1, -2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_rem_euclid_by_zero() {
+        let a = Tensor::from_slice([2], &[1, 2]);
+        let b = Tensor::from_slice([2], &[0, 1]);
+
+        let _ = a.rem_euclid(&b);
+    }
+}
@@ -0,0 +1,241 @@
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use crate::Tensor;
+
+/// Applies `f` to `v` and `n` values of `a`, and writes result to `r`.
+#[inline(always)]
+unsafe fn value_lhs<T, F>(n: usize, v: T, a: *const T, r: *mut T, f: F)
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(f(v, a_i));
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy,
+{
+    /// Applies `f` with `value` as left operand to each element and returns new tensor.
+    fn value_lhs_new<F>(&self, value: T, f: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        let a = &self.data;
+        Tensor::make_with(self.metadata, |i| f(value, unsafe { *a.reference(i) }))
+    }
+
+    /// Applies `f` with `value` as left operand to each element in-place.
+    fn value_lhs_inplace<F>(&mut self, value: T, f: F)
+    where
+        F: Fn(T, T) -> T,
+    {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        unsafe { value_lhs(len, value, a, a, f) }
+    }
+
+    /// Performs in-place element-wise reverse subtraction, replacing each element `x` of `self`
+    /// with `value - x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+    ///
+    /// tensor.rsub_assign(10);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[9, 8, 7, 6]);
+    /// ```
+    pub fn rsub_assign(&mut self, value: T)
+    where
+        T: Sub<Output = T>,
+    {
+        self.value_lhs_inplace(value, |v, x| v - x)
+    }
+
+    /// Performs in-place element-wise reverse division, replacing each element `x` of `self`
+    /// with `value / x`.
+    ///
+    /// # Panics
+    /// This method will panic if any element is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 2], &[1.0, 2.0, 4.0, 8.0]);
+    ///
+    /// tensor.rdiv_assign(1.0);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1.0, 0.5, 0.25, 0.125]);
+    /// ```
+    pub fn rdiv_assign(&mut self, value: T)
+    where
+        T: Div<Output = T>,
+    {
+        self.value_lhs_inplace(value, |v, x| v / x)
+    }
+
+    /// Performs in-place element-wise reverse remainder, replacing each element `x` of `self`
+    /// with `value % x`.
+    ///
+    /// # Panics
+    /// This method will panic if any element is `0` for integer types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 2], &[3, 4, 5, 6]);
+    ///
+    /// tensor.rrem_assign(10);
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1, 2, 0, 4]);
+    /// ```
+    pub fn rrem_assign(&mut self, value: T)
+    where
+        T: Rem<Output = T>,
+    {
+        self.value_lhs_inplace(value, |v, x| v % x)
+    }
+}
+
+/// Implements the arithmetic operators with a scalar of type `T` as left operand and
+/// `&Tensor<T, R>` as right operand, for the primitive numeric types.
+macro_rules! impl_value_lhs {
+    ($($t:ty)*) => {
+        $(
+            impl<const R: usize> Add<&Tensor<$t, R>> for $t {
+                type Output = Tensor<$t, R>;
+
+                /// Performs element-wise addition of `self` to `tensor` and returns result as new
+                /// `Tensor<T, R>`, without affecting `tensor`.
+                #[inline]
+                fn add(self, tensor: &Tensor<$t, R>) -> Tensor<$t, R> {
+                    tensor.value_lhs_new(self, |v, x| v + x)
+                }
+            }
+
+            impl<const R: usize> Sub<&Tensor<$t, R>> for $t {
+                type Output = Tensor<$t, R>;
+
+                /// Performs element-wise subtraction of `tensor` from `self` and returns result
+                /// as new `Tensor<T, R>`, without affecting `tensor`.
+                #[inline]
+                fn sub(self, tensor: &Tensor<$t, R>) -> Tensor<$t, R> {
+                    tensor.value_lhs_new(self, |v, x| v - x)
+                }
+            }
+
+            impl<const R: usize> Mul<&Tensor<$t, R>> for $t {
+                type Output = Tensor<$t, R>;
+
+                /// Performs element-wise multiplication of `self` with `tensor` and returns
+                /// result as new `Tensor<T, R>`, without affecting `tensor`.
+                #[inline]
+                fn mul(self, tensor: &Tensor<$t, R>) -> Tensor<$t, R> {
+                    tensor.value_lhs_new(self, |v, x| v * x)
+                }
+            }
+
+            impl<const R: usize> Div<&Tensor<$t, R>> for $t {
+                type Output = Tensor<$t, R>;
+
+                /// Performs element-wise division of `self` by `tensor` and returns result as new
+                /// `Tensor<T, R>`, without affecting `tensor`.
+                #[inline]
+                fn div(self, tensor: &Tensor<$t, R>) -> Tensor<$t, R> {
+                    tensor.value_lhs_new(self, |v, x| v / x)
+                }
+            }
+
+            impl<const R: usize> Rem<&Tensor<$t, R>> for $t {
+                type Output = Tensor<$t, R>;
+
+                /// Computes element-wise the remainder of `self` divided by `tensor` and returns
+                /// result as new `Tensor<T, R>`, without affecting `tensor`.
+                #[inline]
+                fn rem(self, tensor: &Tensor<$t, R>) -> Tensor<$t, R> {
+                    tensor.value_lhs_new(self, |v, x| v % x)
+                }
+            }
+        )*
+    };
+}
+
+impl_value_lhs!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+#[cfg(test)]
+mod scalar_tests {
+    use super::*;
+
+    #[test]
+    fn test_value_lhs_add_mul() {
+        let tensor = Tensor::from_slice([2, 2], &[1i32, 2, 3, 4]);
+
+        assert_eq!((10 + &tensor).as_slice(), &[11, 12, 13, 14]);
+        assert_eq!((3 * &tensor).as_slice(), &[3, 6, 9, 12]);
+    }
+
+    #[test]
+    fn test_value_lhs_sub() {
+        let tensor = Tensor::from_slice([2, 2], &[1u8, 2, 3, 4]);
+
+        let result = 10 - &tensor;
+
+        assert_eq!(result.as_slice(), &[9, 8, 7, 6]);
+        assert_eq!(tensor.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_value_lhs_div() {
+        let tensor = Tensor::from_slice([2, 2], &[1.0f32, 2.0, 4.0, 8.0]);
+
+        let result = 1.0 / &tensor;
+
+        assert_eq!(result.as_slice(), &[1.0, 0.5, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_value_lhs_rem() {
+        let tensor = Tensor::from_slice([3], &[3i64, 4, 5]);
+
+        assert_eq!((10 % &tensor).as_slice(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn test_reverse_assign() {
+        let mut tensor = Tensor::from_slice([3], &[1.0, 2.0, 4.0]);
+
+        tensor.rsub_assign(2.0);
+        assert_eq!(tensor.as_slice(), &[1.0, 0.0, -2.0]);
+
+        tensor.rdiv_assign(4.0);
+        assert_eq!(tensor.as_slice(), &[4.0, f64::INFINITY, -2.0]);
+
+        let mut tensor = Tensor::from_slice([2], &[4, 7]);
+
+        tensor.rrem_assign(9);
+        assert_eq!(tensor.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reverse_div_by_zero() {
+        let mut tensor = Tensor::from_slice([2], &[1, 0]);
+
+        tensor.rdiv_assign(1);
+    }
+}
@@ -0,0 +1,262 @@
+use core::hint::unreachable_unchecked;
+use core::ops::Shl;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+
+/// Shifts `n` values of `a` to the left by `b` bits and writes result to `r`.
+#[inline(always)]
+unsafe fn shl<T>(n: usize, a: *const T, b: *const T, r: *mut T)
+where
+    T: Copy + Shl<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(a_i << b_i);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Shl<Self> for &Tensor<T, R>
+where
+    T: Copy + Shl<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise left shift between `self` and `other` tensor and returns new
+    /// `Tensor<T, R>` as a result of the shift without consuming `self` or `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// In debug mode, it will panic also if any shift amount is greater than or equal to the
+    /// number of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor1 = Tensor::new_set([2, 3], 1);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// let result = &tensor1 << &tensor2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &8);
+    /// assert_eq!(result.get(&[1, 2]), &8);
+    /// ```
+    fn shl(self, other: Self) -> Tensor<T, R> {
+        assert_same_shape(self, other);
+
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+
+        let a = self.data.as_ptr();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            shl(len, a, b, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Shl<&Tensor<T, R>> for &mut Tensor<T, R>
+where
+    T: Copy + Shl<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise left shift between `self` and another tensor.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// In debug mode, it will panic also if any shift amount is greater than or equal to the
+    /// number of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor1 = Tensor::new_set([2, 3], 1);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// &mut tensor1 << &tensor2;
+    ///
+    /// assert_eq!(tensor1.get(&[0, 0]), &8);
+    /// assert_eq!(tensor1.get(&[1, 2]), &8);
+    /// ```
+    fn shl(self, other: &Tensor<T, R>) {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            shl(len, a, b, a);
+        }
+    }
+}
+
+/// Shifts `n` values of `a` to the left by `v` bits, and writes result to `r`.
+#[inline(always)]
+unsafe fn shl_value<T>(n: usize, a: *const T, v: T, r: *mut T)
+where
+    T: Copy + Shl<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(a_i << v);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Shl<T> for &Tensor<T, R>
+where
+    T: Copy + Shl<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise left shift between `self` and `value` and returns result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// # Panics
+    /// In debug mode, this method will panic if `value` is greater than or equal to the number
+    /// of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 3], 1);
+    ///
+    /// let result = &tensor << 2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &4);
+    /// assert_eq!(result.get(&[1, 2]), &4);
+    /// ```
+    fn shl(self, value: T) -> Tensor<T, R> {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            shl_value(len, a, value, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Shl<T> for &mut Tensor<T, R>
+where
+    T: Copy + Shl<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise left shift between `self` and `value`.
+    ///
+    /// # Panics
+    /// In debug mode, this method will panic if `value` is greater than or equal to the number
+    /// of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 3], 1);
+    ///
+    /// &mut tensor << 2;
+    ///
+    /// assert_eq!(tensor.get(&[0, 0]), &4);
+    /// assert_eq!(tensor.get(&[1, 2]), &4);
+    /// ```
+    fn shl(self, value: T) {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+
+        unsafe {
+            shl_value(len, a, value, a);
+        }
+    }
+}
+
+#[cfg(test)]
+mod shl_tests {
+    use super::*;
+
+    #[test]
+    fn test_shl_new() {
+        let tensor1 = Tensor::from_slice([2, 2], &[1u32, 2, 3, 4]);
+        let tensor2 = Tensor::from_slice([2, 2], &[0, 1, 2, 3]);
+
+        let result = &tensor1 << &tensor2;
+
+        assert_eq!(result.as_slice(), &[1, 4, 12, 32]);
+    }
+
+    #[test]
+    fn test_shl_mutate() {
+        let mut tensor1 = Tensor::from_slice([2, 2], &[1u32, 2, 3, 4]);
+        let tensor2 = Tensor::from_slice([2, 2], &[0, 1, 2, 3]);
+
+        &mut tensor1 << &tensor2;
+
+        assert_eq!(tensor1.as_slice(), &[1, 4, 12, 32]);
+    }
+
+    #[test]
+    fn test_shl_value() {
+        let tensor = Tensor::from_slice([2, 2], &[1u32, 2, 3, 4]);
+
+        let result = &tensor << 4;
+
+        assert_eq!(result.as_slice(), &[16, 32, 48, 64]);
+    }
+
+    #[test]
+    fn test_shl_value_mut() {
+        let mut tensor = Tensor::from_slice([2, 2], &[1u32, 2, 3, 4]);
+
+        &mut tensor << 4;
+
+        assert_eq!(tensor.as_slice(), &[16, 32, 48, 64]);
+    }
+}
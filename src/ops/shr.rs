@@ -0,0 +1,262 @@
+use core::hint::unreachable_unchecked;
+use core::ops::Shr;
+
+use crate::mem::error::OnError;
+use crate::mem::pointers::UnmanagedPointer;
+
+use crate::Tensor;
+use crate::assertions::assert_same_shape;
+
+/// Shifts `n` values of `a` to the right by `b` bits and writes result to `r`.
+#[inline(always)]
+unsafe fn shr<T>(n: usize, a: *const T, b: *const T, r: *mut T)
+where
+    T: Copy + Shr<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            let b_i = *b.add(i);
+            r.add(i).write(a_i >> b_i);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Shr<Self> for &Tensor<T, R>
+where
+    T: Copy + Shr<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise right shift between `self` and `other` tensor and returns new
+    /// `Tensor<T, R>` as a result of the shift without consuming `self` or `other`.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// In debug mode, it will panic also if any shift amount is greater than or equal to the
+    /// number of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor1 = Tensor::new_set([2, 3], 32);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// let result = &tensor1 >> &tensor2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &4);
+    /// assert_eq!(result.get(&[1, 2]), &4);
+    /// ```
+    fn shr(self, other: Self) -> Tensor<T, R> {
+        assert_same_shape(self, other);
+
+        // len is assumed to be > 0.
+        let len = self.metadata.size();
+
+        let a = self.data.as_ptr();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            shr(len, a, b, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Shr<&Tensor<T, R>> for &mut Tensor<T, R>
+where
+    T: Copy + Shr<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise right shift between `self` and another tensor.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of `self` and `other` do not match.
+    /// In debug mode, it will panic also if any shift amount is greater than or equal to the
+    /// number of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor1 = Tensor::new_set([2, 3], 32);
+    /// let tensor2 = Tensor::new_set([2, 3], 3);
+    ///
+    /// &mut tensor1 >> &tensor2;
+    ///
+    /// assert_eq!(tensor1.get(&[0, 0]), &4);
+    /// assert_eq!(tensor1.get(&[1, 2]), &4);
+    /// ```
+    fn shr(self, other: &Tensor<T, R>) {
+        assert_same_shape(self, other);
+
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+        let b = other.data.as_ptr();
+
+        unsafe {
+            shr(len, a, b, a);
+        }
+    }
+}
+
+/// Shifts `n` values of `a` to the right by `v` bits, and writes result to `r`.
+#[inline(always)]
+unsafe fn shr_value<T>(n: usize, a: *const T, v: T, r: *mut T)
+where
+    T: Copy + Shr<Output = T>,
+{
+    let mut i = 0;
+    while i < n {
+        unsafe {
+            let a_i = *a.add(i);
+            r.add(i).write(a_i >> v);
+        }
+        i += 1;
+    }
+}
+
+impl<T, const R: usize> Shr<T> for &Tensor<T, R>
+where
+    T: Copy + Shr<Output = T>,
+{
+    type Output = Tensor<T, R>;
+
+    /// Performs element-wise right shift between `self` and `value` and returns result as new
+    /// `Tensor<T, R>`, without affecting the original instance.
+    ///
+    /// # Panics
+    /// In debug mode, this method will panic if `value` is greater than or equal to the number
+    /// of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::new_set([2, 3], 32);
+    ///
+    /// let result = &tensor >> 2;
+    ///
+    /// assert_eq!(result.get(&[0, 0]), &8);
+    /// assert_eq!(result.get(&[1, 2]), &8);
+    /// ```
+    fn shr(self, value: T) -> Tensor<T, R> {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr();
+
+        unsafe {
+            let mut output = UnmanagedPointer::new();
+
+            let layout = output.layout_unchecked_of(len);
+
+            match output.acquire(layout, OnError::Panic) {
+                Ok(_) => (),
+                Err(_) => unreachable_unchecked(),
+            };
+
+            shr_value(len, a, value, output.as_ptr_mut());
+
+            Tensor {
+                metadata: self.metadata,
+                data: output,
+            }
+        }
+    }
+}
+
+impl<T, const R: usize> Shr<T> for &mut Tensor<T, R>
+where
+    T: Copy + Shr<Output = T>,
+{
+    type Output = ();
+
+    /// Performs in-place element-wise right shift between `self` and `value`.
+    ///
+    /// # Panics
+    /// In debug mode, this method will panic if `value` is greater than or equal to the number
+    /// of bits of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::new_set([2, 3], 32);
+    ///
+    /// &mut tensor >> 2;
+    ///
+    /// assert_eq!(tensor.get(&[0, 0]), &8);
+    /// assert_eq!(tensor.get(&[1, 2]), &8);
+    /// ```
+    fn shr(self, value: T) {
+        let len = self.metadata.size();
+        let a = self.data.as_ptr_mut();
+
+        unsafe {
+            shr_value(len, a, value, a);
+        }
+    }
+}
+
+#[cfg(test)]
+mod shr_tests {
+    use super::*;
+
+    #[test]
+    fn test_shr_new() {
+        let tensor1 = Tensor::from_slice([2, 2], &[64i32, -64, 7, 1]);
+        let tensor2 = Tensor::from_slice([2, 2], &[1, 2, 1, 1]);
+
+        let result = &tensor1 >> &tensor2;
+
+        assert_eq!(result.as_slice(), &[32, -16, 3, 0]);
+    }
+
+    #[test]
+    fn test_shr_mutate() {
+        let mut tensor1 = Tensor::from_slice([2, 2], &[64i32, -64, 7, 1]);
+        let tensor2 = Tensor::from_slice([2, 2], &[1, 2, 1, 1]);
+
+        &mut tensor1 >> &tensor2;
+
+        assert_eq!(tensor1.as_slice(), &[32, -16, 3, 0]);
+    }
+
+    #[test]
+    fn test_shr_value() {
+        let tensor = Tensor::from_slice([2, 2], &[64i32, -64, 7, 1]);
+
+        let result = &tensor >> 1;
+
+        assert_eq!(result.as_slice(), &[32, -32, 3, 0]);
+    }
+
+    #[test]
+    fn test_shr_value_mut() {
+        let mut tensor = Tensor::from_slice([2, 2], &[64i32, -64, 7, 1]);
+
+        &mut tensor >> 1;
+
+        assert_eq!(tensor.as_slice(), &[32, -32, 3, 0]);
+    }
+}
@@ -1,10 +1,172 @@
 use core::ops::{AddAssign, Mul};
 
 use crate::assertions::assert_same_shape;
+use crate::num::{Accumulator, Summation};
 use crate::tensor::Tensor;
 
+// Kernels of the metrics between two lanes, given as the pairs of their values converted to
+// `f64`. They are shared by the methods below and the pairwise computations.
+
+/// Returns the dot product of the lanes.
+pub(crate) fn dot_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut sum = Accumulator::new(summation);
+    for (x_i, y_i) in pairs {
+        sum.add(x_i * y_i);
+    }
+    sum.sum()
+}
+
+/// Returns the cosine similarity of the lanes, `0.0` if either of them is zero.
+pub(crate) fn cosine_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut product_x_y = Accumulator::new(summation);
+    let mut sum_exp_x = Accumulator::new(summation);
+    let mut sum_exp_y = Accumulator::new(summation);
+
+    for (x_i, y_i) in pairs {
+        product_x_y.add(x_i * y_i);
+        sum_exp_x.add(x_i * x_i);
+        sum_exp_y.add(y_i * y_i);
+    }
+
+    let e_norm_x = sum_exp_x.sum().sqrt();
+    let e_norm_y = sum_exp_y.sum().sqrt();
+
+    if e_norm_x == 0.0 || e_norm_y == 0.0 {
+        0.0
+    } else {
+        // Negative values are NaN for square root, and all ops with NaN return NaN.
+        product_x_y.sum() / (e_norm_x * e_norm_y)
+    }
+}
+
+/// Returns the squared Euclidean distance between the lanes.
+pub(crate) fn squared_euclidean_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut sum = Accumulator::new(summation);
+    for (x_i, y_i) in pairs {
+        let delta = x_i - y_i;
+        sum.add(delta * delta);
+    }
+    sum.sum()
+}
+
+/// Returns the Manhattan distance between the lanes.
+pub(crate) fn manhattan_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut sum = Accumulator::new(summation);
+    for (x_i, y_i) in pairs {
+        sum.add((x_i - y_i).abs());
+    }
+    sum.sum()
+}
+
+/// Returns the Chebyshev distance between the lanes, NaN if any difference is NaN.
+pub(crate) fn chebyshev_of<I>(pairs: I) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut max: f64 = 0.0;
+    for (x_i, y_i) in pairs {
+        let delta = (x_i - y_i).abs();
+        if delta > max || delta.is_nan() {
+            max = delta;
+        }
+    }
+    max
+}
+
+/// Returns the Minkowski distance of order `p` between the lanes, which must be at least `1`.
+pub(crate) fn minkowski_of<I>(pairs: I, p: f64, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    if p == f64::INFINITY {
+        return chebyshev_of(pairs);
+    }
+
+    let mut sum = Accumulator::new(summation);
+    for (x_i, y_i) in pairs {
+        sum.add((x_i - y_i).abs().powf(p));
+    }
+    sum.sum().powf(p.recip())
+}
+
+/// Returns the Canberra distance between the lanes, where pairs of zeros add nothing.
+pub(crate) fn canberra_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut sum = Accumulator::new(summation);
+    for (x_i, y_i) in pairs {
+        let denominator = x_i.abs() + y_i.abs();
+        if denominator != 0.0 {
+            sum.add((x_i - y_i).abs() / denominator);
+        }
+    }
+    sum.sum()
+}
+
+/// Returns the Bray-Curtis distance between the lanes, `0.0` if the denominator is `0`.
+pub(crate) fn bray_curtis_of<I>(pairs: I, summation: Summation) -> f64
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let mut sum_delta = Accumulator::new(summation);
+    let mut sum_total = Accumulator::new(summation);
+
+    for (x_i, y_i) in pairs {
+        sum_delta.add((x_i - y_i).abs());
+        sum_total.add((x_i + y_i).abs());
+    }
+
+    let total = sum_total.sum();
+
+    if total == 0.0 {
+        0.0
+    } else {
+        sum_delta.sum() / total
+    }
+}
+
+/// Returns the number of pairs whose values differ.
+pub(crate) fn hamming_of<U, I>(pairs: I) -> usize
+where
+    U: PartialEq,
+    I: IntoIterator<Item = (U, U)>,
+{
+    pairs.into_iter().filter(|(x_i, y_i)| x_i != y_i).count()
+}
+
 impl<T, const R: usize> Tensor<T, R> {
-    /// Returns the dot product of two tensors.
+    /// Returns the pairs of elements of two tensors converted to `f64`, in row-major order.
+    ///
+    /// # Panics
+    /// This method will panic if the shapes of the tensors differ.
+    fn pairs<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (f64, f64)> + 'a
+    where
+        T: Copy + Into<f64>,
+    {
+        assert_same_shape(self, other);
+
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(&a_i, &b_i)| (a_i.into(), b_i.into()))
+    }
+
+    /// Returns the dot product of two tensors, accumulated naively in `T`.
+    ///
+    /// See [`Tensor::dot_product_with`] to accumulate with a summation strategy.
     pub fn dot_product(&self, other: &Self) -> T
     where
         T: Copy + Default + AddAssign<T> + Mul<Output = T>,
@@ -30,10 +192,119 @@ impl<T, const R: usize> Tensor<T, R> {
         product
     }
 
-    /// Returns the cosine similarity between two tensors.
+    /// Returns the dot product of two tensors, accumulated as `f64` with the provided summation
+    /// strategy.
+    ///
+    /// Unlike [`Tensor::dot_product`], which accumulates naively in `T`, the result is accurate
+    /// for long float tensors when `summation` is not `Naive`.
+    pub fn dot_product_with(&self, other: &Self, summation: Summation) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        dot_of(self.pairs(other), summation)
+    }
+
+    /// Returns the cosine similarity between two tensors, accumulated with the global summation
+    /// strategy.
     pub fn cosine_similarity(&self, other: &Self) -> f64
     where
         T: Copy + Default + Into<f64>,
+    {
+        self.cosine_similarity_with(other, Summation::global())
+    }
+
+    /// Returns the cosine similarity between two tensors, accumulated with the provided
+    /// summation strategy.
+    pub fn cosine_similarity_with(&self, other: &Self, summation: Summation) -> f64
+    where
+        T: Copy + Default + Into<f64>,
+    {
+        cosine_of(self.pairs(other), summation)
+    }
+
+    /// Returns the Euclidean distance between two tensors, accumulated with the global summation
+    /// strategy.
+    pub fn euclidean_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Default + Into<f64>,
+    {
+        self.euclidean_distance_with(other, Summation::global())
+    }
+
+    /// Returns the Euclidean distance between two tensors, accumulated with the provided
+    /// summation strategy.
+    pub fn euclidean_distance_with(&self, other: &Self, summation: Summation) -> f64
+    where
+        T: Copy + Default + Into<f64>,
+    {
+        squared_euclidean_of(self.pairs(other), summation).sqrt()
+    }
+
+    /// Returns the squared Euclidean distance between two tensors.
+    pub fn squared_euclidean_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        squared_euclidean_of(self.pairs(other), Summation::global())
+    }
+
+    /// Returns the Manhattan (city block) distance between two tensors, the sum of the absolute
+    /// differences.
+    pub fn manhattan_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        manhattan_of(self.pairs(other), Summation::global())
+    }
+
+    /// Returns the Chebyshev distance between two tensors, the greatest absolute difference.
+    /// NaN is returned if any difference is NaN.
+    pub fn chebyshev_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        chebyshev_of(self.pairs(other))
+    }
+
+    /// Returns the Minkowski distance of order `p` between two tensors,
+    /// `(sum |a - b|^p)^(1/p)`. `p` of infinity gives the Chebyshev distance.
+    ///
+    /// # Panics
+    /// This method will panic if `p` is less than `1` or NaN.
+    pub fn minkowski_distance(&self, other: &Self, p: f64) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        assert!(p >= 1.0, "Invalid norm order");
+
+        minkowski_of(self.pairs(other), p, Summation::global())
+    }
+
+    /// Returns the Canberra distance between two tensors, `sum |a - b| / (|a| + |b|)`, where
+    /// pairs of zeros add nothing.
+    pub fn canberra_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        canberra_of(self.pairs(other), Summation::global())
+    }
+
+    /// Returns the Bray-Curtis distance between two tensors, `sum |a - b| / sum |a + b|`, or `0.0`
+    /// if the denominator is `0`.
+    pub fn bray_curtis_distance(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        bray_curtis_of(self.pairs(other), Summation::global())
+    }
+
+    /// Returns the Pearson correlation coefficient between two tensors, or NaN if either of them
+    /// has zero variance, as `corrcoef` does.
+    ///
+    /// The means and co-moments are updated in a single pass with Welford's algorithm.
+    pub fn pearson_correlation(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
     {
         assert_same_shape(self, other);
 
@@ -41,58 +312,81 @@ impl<T, const R: usize> Tensor<T, R> {
         let a = &self.data;
         let b = &other.data;
 
-        let mut product_a_b: f64 = 0.0;
-        let mut sum_exp_a: f64 = 0.0;
-        let mut sum_exp_b: f64 = 0.0;
+        let mut mean_a: f64 = 0.0;
+        let mut mean_b: f64 = 0.0;
+        let mut m2_a: f64 = 0.0;
+        let mut m2_b: f64 = 0.0;
+        let mut m2_a_b: f64 = 0.0;
 
         unsafe {
             let mut i = 0;
             while i < len {
                 let a_i: f64 = (*a.reference(i)).into();
                 let b_i: f64 = (*b.reference(i)).into();
-                product_a_b += a_i * b_i;
-                sum_exp_a += a_i * a_i;
-                sum_exp_b += b_i * b_i;
+                let n = (i + 1) as f64;
+                let delta_a = a_i - mean_a;
+                let delta_b = b_i - mean_b;
+                mean_a += delta_a / n;
+                mean_b += delta_b / n;
+                m2_a += delta_a * (a_i - mean_a);
+                m2_b += delta_b * (b_i - mean_b);
+                m2_a_b += delta_a * (b_i - mean_b);
                 i += 1;
             }
         }
 
-        let e_norm_a: f64 = sum_exp_a.sqrt();
-        let e_norm_b: f64 = sum_exp_b.sqrt();
-
-        if e_norm_a == 0.0 || e_norm_b == 0.0 {
-            0.0
+        if m2_a == 0.0 || m2_b == 0.0 {
+            f64::NAN
         } else {
-            // Negative values are NaN for square root, and all ops with NaN return NaN.
-            product_a_b / (e_norm_a * e_norm_b)
+            // Rounding errors may push the coefficient slightly out of [-1, 1].
+            (m2_a_b / (m2_a * m2_b).sqrt()).clamp(-1.0, 1.0)
         }
     }
 
-    /// Returns the Euclidean distance between two tensors.
-    pub fn euclidean_distance(&self, other: &Self) -> f64
+    /// Returns the Hamming distance between two tensors, the number of positions at which the
+    /// elements differ.
+    pub fn hamming_distance(&self, other: &Self) -> usize
     where
-        T: Copy + Default + Into<f64>,
+        T: PartialEq,
+    {
+        assert_same_shape(self, other);
+
+        hamming_of(self.as_slice().iter().zip(other.as_slice()))
+    }
+
+    /// Returns the Jaccard distance between two tensors seen as sets of the positions of their
+    /// non-default (non-zero or `true`) elements, `1 - |A ∩ B| / |A ∪ B|`, or `0.0` if both sets
+    /// are empty.
+    pub fn jaccard_distance(&self, other: &Self) -> f64
+    where
+        T: Default + PartialEq,
     {
         assert_same_shape(self, other);
 
         let len = self.metadata.size();
         let a = &self.data;
         let b = &other.data;
+        let zero = T::default();
 
-        let mut sum: f64 = 0.0;
+        let mut intersection: usize = 0;
+        let mut union: usize = 0;
 
         unsafe {
             let mut i = 0;
             while i < len {
-                let a_i: f64 = (*a.reference(i)).into();
-                let b_i: f64 = (*b.reference(i)).into();
-                let delta = a_i - b_i;
-                sum += delta * delta;
+                let in_a = *a.reference(i) != zero;
+                let in_b = *b.reference(i) != zero;
+                intersection += (in_a && in_b) as usize;
+                union += (in_a || in_b) as usize;
                 i += 1;
             }
         }
 
-        sum.sqrt()
+        if union == 0 {
+            0.0
+        } else {
+            1.0 - intersection as f64 / union as f64
+        }
     }
 }
 
@@ -149,4 +443,103 @@ mod tests {
 
         assert_eq!(result, 18.0);
     }
+
+    #[test]
+    fn test_dot_product_with() {
+        // The exact dot product is 2, the naive sum loses both ones to the large products.
+        let tensor1 = Tensor::from_slice([4], &[1.0, 1e50, 1.0, -1e50]);
+        let tensor2 = Tensor::from_slice([4], &[1.0, 1e50, 1.0, 1e50]);
+
+        assert_eq!(tensor1.dot_product_with(&tensor2, Summation::Naive), 0.0);
+        assert_eq!(
+            tensor1.dot_product_with(&tensor2, Summation::KahanBabuska),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_euclidean_distance_with() {
+        let n = 1 << 16;
+        let tensor1 = Tensor::new_set([n], 0.1);
+        let tensor2 = Tensor::new_set([n], 0.2);
+
+        let delta: f64 = 0.2 - 0.1;
+        let exact = (delta * delta * n as f64).sqrt();
+
+        let result = tensor1.euclidean_distance_with(&tensor2, Summation::Pairwise);
+
+        assert!((result - exact).abs() <= 4.0 * f64::EPSILON * exact);
+    }
+
+    #[test]
+    fn test_squared_euclidean_manhattan_chebyshev() {
+        let tensor1 = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+        let tensor2 = Tensor::from_slice([2, 2], &[2, 0, 3, 8]);
+
+        assert_eq!(tensor1.squared_euclidean_distance(&tensor2), 21.0);
+        assert_eq!(tensor1.manhattan_distance(&tensor2), 7.0);
+        assert_eq!(tensor1.chebyshev_distance(&tensor2), 4.0);
+    }
+
+    #[test]
+    fn test_minkowski_distance() {
+        let tensor1 = Tensor::from_slice([3], &[0.0, 0.0, 0.0]);
+        let tensor2 = Tensor::from_slice([3], &[3.0, -4.0, 1.0]);
+
+        assert_eq!(tensor1.minkowski_distance(&tensor2, 1.0), 8.0);
+        assert!((tensor1.minkowski_distance(&tensor2, 2.0) - 26f64.sqrt()).abs() < 1e-12);
+        assert_eq!(tensor1.minkowski_distance(&tensor2, f64::INFINITY), 4.0);
+    }
+
+    #[test]
+    fn test_canberra_bray_curtis() {
+        let tensor1 = Tensor::from_slice([3], &[1.0, 0.0, 2.0]);
+        let tensor2 = Tensor::from_slice([3], &[3.0, 0.0, 2.0]);
+
+        assert_eq!(tensor1.canberra_distance(&tensor2), 0.5);
+        assert_eq!(tensor1.bray_curtis_distance(&tensor2), 0.25);
+
+        let zeros = Tensor::new_set([3], 0.0);
+
+        assert_eq!(zeros.bray_curtis_distance(&zeros), 0.0);
+    }
+
+    #[test]
+    fn test_pearson_correlation() {
+        let tensor1 = Tensor::from_slice([4], &[1.0, 2.0, 3.0, 4.0]);
+        let tensor2 = Tensor::from_slice([4], &[2.0, 1.0, 4.0, 3.0]);
+        let tensor3 = Tensor::from_slice([4], &[1e9 + 8.0, 1e9 + 6.0, 1e9 + 4.0, 1e9 + 2.0]);
+
+        assert!((tensor1.pearson_correlation(&tensor2) - 0.6).abs() < 1e-12);
+        assert!((tensor1.pearson_correlation(&tensor3) + 1.0).abs() < 1e-12);
+        assert!(
+            tensor1
+                .pearson_correlation(&Tensor::new_set([4], 5.0))
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn test_hamming_jaccard() {
+        let tensor1 = Tensor::from_slice([5], &[true, true, false, false, true]);
+        let tensor2 = Tensor::from_slice([5], &[true, false, false, true, true]);
+
+        assert_eq!(tensor1.hamming_distance(&tensor2), 2);
+        assert_eq!(tensor1.jaccard_distance(&tensor2), 0.5);
+
+        let tensor1 = Tensor::from_slice([3], &[0u8, 7, 0]);
+        let tensor2 = Tensor::from_slice([3], &[0u8, 3, 0]);
+
+        assert_eq!(tensor1.hamming_distance(&tensor2), 1);
+        assert_eq!(tensor1.jaccard_distance(&tensor2), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_manhattan_distance_shape_mismatch() {
+        let tensor1 = Tensor::new_set([2, 3], 1.0);
+        let tensor2 = Tensor::new_set([3, 2], 1.0);
+
+        let _ = tensor1.manhattan_distance(&tensor2);
+    }
 }
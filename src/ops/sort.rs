@@ -0,0 +1,305 @@
+use core::cmp::Ordering;
+use core::ptr;
+
+use crate::Tensor;
+use crate::assertions::assert_valid_axis;
+use crate::num::TotalOrder;
+
+/// Writes to `order` the positions of the `len` values of the lane starting at `base`, with
+/// `inner` distance between them, in the stable order given by `cmp`.
+#[inline(always)]
+unsafe fn lane_order<T, F>(
+    base: *const T,
+    len: usize,
+    inner: usize,
+    cmp: &mut F,
+    order: &mut Vec<usize>,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    order.clear();
+    order.extend(0..len);
+    order.sort_by(|&x, &y| unsafe { cmp(&*base.add(x * inner), &*base.add(y * inner)) });
+}
+
+impl<T, const R: usize> Tensor<T, R> {
+    /// Sorts in-place each lane along `axis` with the comparator function `cmp`.
+    ///
+    /// The sort is stable, equal elements keep their order. If `cmp` panics, `self` is left
+    /// unchanged for the lane being sorted.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 3], &[1, -5, 3, 2, 4, -6]);
+    ///
+    /// tensor.sort_axis_by(1, |a: &i32, b: &i32| a.abs().cmp(&b.abs()));
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1, 3, -5, 2, 4, -6]);
+    /// ```
+    pub fn sort_axis_by<F>(&mut self, axis: usize, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert_valid_axis::<R>(axis);
+
+        let (outer, len, inner) = self.metadata.split_at_axis(axis);
+        let a = self.data.as_ptr_mut();
+
+        let mut order: Vec<usize> = Vec::with_capacity(len);
+        let mut lane: Vec<T> = Vec::with_capacity(len);
+        let l = lane.as_mut_ptr();
+
+        let mut o = 0;
+        while o < outer {
+            let mut i = 0;
+            while i < inner {
+                unsafe {
+                    let base = a.add(o * len * inner + i);
+
+                    lane_order(base, len, inner, &mut cmp, &mut order);
+
+                    // Values are moved to `lane` without changing its length, so that they are
+                    // owned only by `self` when written back.
+                    let mut r = 0;
+                    while r < len {
+                        l.add(r).write(ptr::read(base.add(order[r] * inner)));
+                        r += 1;
+                    }
+
+                    let mut r = 0;
+                    while r < len {
+                        base.add(r * inner).write(ptr::read(l.add(r)));
+                        r += 1;
+                    }
+                }
+                i += 1;
+            }
+            o += 1;
+        }
+    }
+
+    /// Sorts in-place each lane along `axis` in ascending order, as given by `TotalOrder`.
+    ///
+    /// The sort is stable. For floats, the order is the one of `total_cmp`: negative NaN first,
+    /// `-0.0` before `0.0`, and positive NaN last.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 3], &[0.5, f64::NAN, 0.1, 0.9, 0.3, 0.4]);
+    ///
+    /// tensor.sort_axis(0);
+    ///
+    /// assert_eq!(tensor.as_slice()[..3], [0.5, 0.3, 0.1]);
+    /// assert_eq!(tensor.as_slice()[3], 0.9);
+    /// assert!(tensor.as_slice()[4].is_nan());
+    /// ```
+    pub fn sort_axis(&mut self, axis: usize)
+    where
+        T: TotalOrder,
+    {
+        self.sort_axis_by(axis, T::total_order)
+    }
+
+    /// Returns for each lane along `axis` the positions of its elements in the order that would
+    /// sort the lane in ascending order, as given by `TotalOrder`.
+    ///
+    /// The sort is stable, positions of equal elements are in increasing order.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let scores = Tensor::from_slice([2, 3], &[0.2, 0.9, 0.1, 0.5, 0.5, 0.4]);
+    ///
+    /// let ranking = scores.argsort_axis(1);
+    ///
+    /// assert_eq!(ranking.as_slice(), &[2, 0, 1, 2, 0, 1]);
+    /// ```
+    pub fn argsort_axis(&self, axis: usize) -> Tensor<usize, R>
+    where
+        T: TotalOrder,
+    {
+        assert_valid_axis::<R>(axis);
+
+        let (outer, len, inner) = self.metadata.split_at_axis(axis);
+        let a = self.data.as_ptr();
+
+        let output = Tensor::new_set(self.metadata.dims(), 0usize);
+        let r = output.data.as_ptr_mut();
+
+        let mut order: Vec<usize> = Vec::with_capacity(len);
+        let mut cmp = T::total_order;
+
+        let mut o = 0;
+        while o < outer {
+            let mut i = 0;
+            while i < inner {
+                let start = o * len * inner + i;
+
+                unsafe {
+                    lane_order(a.add(start), len, inner, &mut cmp, &mut order);
+
+                    let mut k = 0;
+                    while k < len {
+                        r.add(start + k * inner).write(order[k]);
+                        k += 1;
+                    }
+                }
+                i += 1;
+            }
+            o += 1;
+        }
+
+        output
+    }
+
+    /// Sorts in-place all the elements of `self` in row-major order in ascending order, as given
+    /// by `TotalOrder`, keeping the shape.
+    ///
+    /// The sort is stable. For floats, the order is the one of `total_cmp`: negative NaN first,
+    /// `-0.0` before `0.0`, and positive NaN last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let mut tensor = Tensor::from_slice([2, 2], &[4, 1, 3, 2]);
+    ///
+    /// tensor.sort();
+    ///
+    /// assert_eq!(tensor.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: TotalOrder,
+    {
+        self.as_slice_mut().sort_by(T::total_order);
+    }
+}
+
+#[cfg(test)]
+mod sort_tests {
+    use super::*;
+
+    use std::rc::Rc;
+
+    #[test]
+    fn test_sort_axis() {
+        let mut tensor = Tensor::from_slice([2, 3], &[3, 1, 2, 0, 5, 4]);
+
+        let mut rows = tensor.clone();
+        rows.sort_axis(1);
+        assert_eq!(rows.as_slice(), &[1, 2, 3, 0, 4, 5]);
+
+        tensor.sort_axis(0);
+        assert_eq!(tensor.as_slice(), &[0, 1, 2, 3, 5, 4]);
+    }
+
+    #[test]
+    fn test_sort_axis_middle() {
+        let mut tensor = Tensor::from_slice([2, 3, 2], &[5, 0, 1, 2, 3, 1, 0, 9, 2, 8, 1, 7]);
+
+        tensor.sort_axis(1);
+
+        assert_eq!(tensor.as_slice(), &[1, 0, 3, 1, 5, 2, 0, 7, 1, 8, 2, 9]);
+    }
+
+    #[test]
+    fn test_sort_axis_by_stable() {
+        let mut tensor = Tensor::from_slice(
+            [6],
+            &[(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (1, 'e'), (0, 'f')],
+        );
+
+        tensor.sort_axis_by(0, |a, b| b.0.cmp(&a.0));
+
+        assert_eq!(
+            tensor.as_slice(),
+            &[(1, 'a'), (1, 'c'), (1, 'e'), (0, 'b'), (0, 'd'), (0, 'f')]
+        );
+    }
+
+    #[test]
+    fn test_sort_axis_by_non_copy() {
+        let values: Vec<Rc<i32>> = [3, 1, 2].into_iter().map(Rc::new).collect();
+        let mut tensor = Tensor::from_boxed_slice([3], values.clone().into_boxed_slice());
+
+        tensor.sort_axis_by(0, |a, b| a.cmp(b));
+
+        assert_eq!(tensor.as_slice(), &[Rc::new(1), Rc::new(2), Rc::new(3)]);
+        assert!(values.iter().all(|v| Rc::strong_count(v) == 2));
+    }
+
+    #[test]
+    fn test_sort_axis_by_panic_unchanged() {
+        let mut tensor = Tensor::from_slice([3], &[3, 1, 2]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tensor.sort_axis_by(0, |_: &i32, _: &i32| panic!())
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(tensor.as_slice(), &[3, 1, 2]);
+    }
+
+    #[test]
+    fn test_argsort_axis() {
+        let tensor = Tensor::from_slice([3, 2], &[1.0, 6.0, f64::NAN, 2.0, -3.0, 2.0]);
+
+        let columns = tensor.argsort_axis(0);
+
+        assert_eq!(columns.shape(), &[3, 2]);
+        assert_eq!(columns.as_slice(), &[2, 1, 0, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut tensor = Tensor::from_slice([2, 3], &[2.5, f32::NAN, -1.0, 0.0, 8.0, -0.0]);
+
+        tensor.sort();
+
+        assert_eq!(tensor.as_slice()[..5], [-1.0, -0.0, 0.0, 2.5, 8.0]);
+        assert!(tensor.as_slice()[5].is_nan());
+        assert!(tensor.as_slice()[1].is_sign_negative());
+    }
+
+    #[test]
+    fn test_sort_matches_total_cmp() {
+        let values = [0.0, -0.0, -f64::NAN, f64::NAN];
+
+        let mut expected = values;
+        expected.sort_by(f64::total_cmp);
+
+        let mut tensor = Tensor::from_slice([4], &values);
+        tensor.sort();
+
+        let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(tensor.as_slice()), bits(&expected));
+
+        let mut lanes = Tensor::from_slice([4, 1], &values);
+        lanes.sort_axis(0);
+
+        assert_eq!(bits(lanes.as_slice()), bits(&expected));
+        assert_eq!(
+            Tensor::from_slice([4], &values).argsort_axis(0).as_slice(),
+            &[2, 1, 0, 3]
+        );
+    }
+}
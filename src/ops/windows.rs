@@ -0,0 +1,211 @@
+use crate::Tensor;
+use crate::metadata::TensorMetadata;
+
+/// A view of a window of a tensor, borrowing the tensor's values.
+#[derive(Debug, Clone, Copy)]
+pub struct Window<'a, T, const R: usize> {
+    tensor: &'a Tensor<T, R>,
+    origin: [usize; R],
+    shape: [usize; R],
+}
+
+impl<'a, T, const R: usize> Window<'a, T, R> {
+    /// Returns the shape of the window.
+    pub const fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the index in the tensor of the window's first value.
+    pub const fn origin(&self) -> [usize; R] {
+        self.origin
+    }
+
+    /// Returns the offset in the tensor of the `p`-th value of the window in row-major order.
+    fn offset_of(&self, mut p: usize) -> usize {
+        let strides = self.tensor.metadata.strides();
+        let mut offset = 0;
+        let mut k = R;
+        while k > 0 {
+            k -= 1;
+            offset += (self.origin[k] + p % self.shape[k]) * strides[k];
+            p /= self.shape[k];
+        }
+        offset
+    }
+
+    /// Returns a reference to the value at the specified index within the window.
+    ///
+    /// # Panics
+    /// This method will panic if any of the indices are out of the window's bounds.
+    pub fn get(&self, index: &[usize; R]) -> &'a T {
+        let mut absolute = self.origin;
+        for k in 0..R {
+            assert!(index[k] < self.shape[k], "Index out of bounds");
+            absolute[k] += index[k];
+        }
+        self.tensor.get(&absolute)
+    }
+
+    /// Returns an iterator over the values of the window in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let values = self.tensor.as_slice();
+        let size: usize = self.shape.iter().product();
+
+        (0..size).map(move |p| &values[self.offset_of(p)])
+    }
+
+    /// Copies the values of the window into a new tensor.
+    pub fn to_tensor(&self) -> Tensor<T, R>
+    where
+        T: Copy,
+    {
+        let values = self.tensor.as_slice();
+
+        Tensor::make_with(TensorMetadata::new(self.shape), |p| {
+            values[self.offset_of(p)]
+        })
+    }
+}
+
+/// An iterator over the windows of a tensor, in row-major order of their origins.
+#[derive(Debug, Clone)]
+pub struct Windows<'a, T, const R: usize> {
+    tensor: &'a Tensor<T, R>,
+    window: [usize; R],
+    strides: [usize; R],
+    counts: [usize; R],
+    next: usize,
+    len: usize,
+}
+
+impl<T, const R: usize> Windows<'_, T, R> {
+    /// Returns the number of windows along each axis.
+    pub const fn shape(&self) -> &[usize] {
+        &self.counts
+    }
+}
+
+impl<'a, T, const R: usize> Iterator for Windows<'a, T, R> {
+    type Item = Window<'a, T, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            return None;
+        }
+
+        let mut origin = [0; R];
+        let mut p = self.next;
+        let mut k = R;
+        while k > 0 {
+            k -= 1;
+            origin[k] = p % self.counts[k] * self.strides[k];
+            p /= self.counts[k];
+        }
+        self.next += 1;
+
+        Some(Window {
+            tensor: self.tensor,
+            origin,
+            shape: self.window,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const R: usize> ExactSizeIterator for Windows<'_, T, R> {}
+
+impl<T, const R: usize> Tensor<T, R> {
+    /// Returns an iterator over the views of the windows of shape `window_shape` whose origins
+    /// are `strides` apart along each axis, in row-major order of their origins.
+    ///
+    /// Windows that would extend past the end of an axis are not included.
+    ///
+    /// # Panics
+    /// This method will panic if a dimension of `window_shape` is `0` or larger than the
+    /// tensor's, or if a stride is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([3, 3], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let windows = tensor.windows([2, 2], [1, 1]);
+    ///
+    /// assert_eq!(windows.shape(), &[2, 2]);
+    ///
+    /// let sums: Vec<i32> = windows.map(|w| w.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, [12, 16, 24, 28]);
+    /// ```
+    pub fn windows(&self, window_shape: [usize; R], strides: [usize; R]) -> Windows<'_, T, R> {
+        let dims = self.metadata.dims();
+        let mut counts = [0; R];
+
+        for k in 0..R {
+            assert!(
+                window_shape[k] > 0 && window_shape[k] <= dims[k],
+                "Window larger than input"
+            );
+            assert!(strides[k] > 0, "Stride must be greater than `0`");
+
+            counts[k] = (dims[k] - window_shape[k]) / strides[k] + 1;
+        }
+
+        Windows {
+            tensor: self,
+            window: window_shape,
+            strides,
+            counts,
+            next: 0,
+            len: counts.iter().product(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod windows_tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_views() {
+        let tensor = Tensor::make_with(TensorMetadata::new([3, 4, 5]), |p| p);
+
+        let windows = tensor.windows([2, 2, 3], [1, 2, 2]);
+
+        assert_eq!(windows.shape(), &[2, 2, 2]);
+        assert_eq!(windows.len(), 8);
+
+        for (n, window) in windows.enumerate() {
+            let [i, j, k] = window.origin();
+
+            assert_eq!([i, j / 2, k / 2], [n / 4, n / 2 % 2, n % 2]);
+            assert_eq!(window.shape(), &[2, 2, 3]);
+            assert_eq!(window.get(&[1, 1, 2]), tensor.get(&[i + 1, j + 1, k + 2]));
+
+            let copy = window.to_tensor();
+            assert!(copy.iter().eq(window.iter()));
+            assert_eq!(copy.get(&[0, 1, 0]), tensor.get(&[i, j + 1, k]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_window_get_out_of_bounds() {
+        let tensor = Tensor::new_set([4, 4], 0);
+
+        let window = tensor.windows([2, 2], [2, 2]).next().unwrap();
+        window.get(&[2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window larger than input")]
+    fn test_window_too_large() {
+        Tensor::new_set([2, 3], 0).windows([2, 4], [1, 1]);
+    }
+}
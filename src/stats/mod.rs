@@ -0,0 +1,5 @@
+mod moments;
+mod quantile;
+
+// Public exports
+pub use quantile::Interpolation;
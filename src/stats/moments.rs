@@ -0,0 +1,318 @@
+use crate::Tensor;
+use crate::assertions::{assert_reduced_rank, assert_valid_axis};
+use crate::mem::pointers::UnmanagedPointer;
+use crate::metadata::TensorMetadata;
+use crate::num::{Accumulator, Summation};
+
+/// Returns the mean and the sum of squared deviations from the mean of `n` values of `a`, with
+/// `step` distance between them, computed with Welford's algorithm.
+#[inline(always)]
+unsafe fn welford<T>(n: usize, step: usize, a: &UnmanagedPointer<T>, start: usize) -> (f64, f64)
+where
+    T: Copy + Into<f64>,
+{
+    let mut mean: f64 = 0.0;
+    let mut m2: f64 = 0.0;
+
+    let mut k = 0;
+    while k < n {
+        let x: f64 = unsafe { (*a.reference(start + k * step)).into() };
+        let delta = x - mean;
+        mean += delta / (k + 1) as f64;
+        m2 += delta * (x - mean);
+        k += 1;
+    }
+
+    (mean, m2)
+}
+
+/// Returns the variance for the sum of squared deviations `m2` of `n` values, with `ddof` delta
+/// degrees of freedom, or NaN if `n` is not greater than `ddof`.
+#[inline(always)]
+fn variance(m2: f64, n: usize, ddof: usize) -> f64 {
+    if n > ddof {
+        m2 / (n - ddof) as f64
+    } else {
+        f64::NAN
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the arithmetic mean of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(tensor.mean(), 2.5);
+    /// ```
+    pub fn mean(&self) -> f64 {
+        let len = self.metadata.size();
+        unsafe { welford(len, 1, &self.data, 0).0 }
+    }
+
+    /// Returns the variance of the elements, computed with Welford's algorithm.
+    ///
+    /// The divisor is `n - ddof` where `n` is the number of elements, so `ddof` of `0` gives the
+    /// population variance and `ddof` of `1` the sample variance. Returns NaN if `n` is not
+    /// greater than `ddof`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([4], &[2.0f32, 4.0, 4.0, 6.0]);
+    ///
+    /// assert!((tensor.var(0) - 2.0).abs() < 1e-12);
+    /// assert!((tensor.var(1) - 8.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn var(&self, ddof: usize) -> f64 {
+        let len = self.metadata.size();
+        let (_, m2) = unsafe { welford(len, 1, &self.data, 0) };
+        variance(m2, len, ddof)
+    }
+
+    /// Returns the standard deviation of the elements, the square root of [`Tensor::var`].
+    pub fn std(&self, ddof: usize) -> f64 {
+        self.var(ddof).sqrt()
+    }
+
+    /// Returns the arithmetic mean of each lane along `axis`, as `Tensor<f64, N>` with the
+    /// dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    pub fn mean_axis<const N: usize>(&self, axis: usize) -> Tensor<f64, N> {
+        self.welford_axis(axis, |mean, _, _| mean)
+    }
+
+    /// Returns the variance of each lane along `axis` with `ddof` delta degrees of freedom, as
+    /// `Tensor<f64, N>` with the dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 3], &[1, 2, 3, 2, 4, 6]);
+    ///
+    /// let rows: Tensor<f64, 1> = tensor.var_axis(1, 1);
+    ///
+    /// assert_eq!(rows.as_slice(), &[1.0, 4.0]);
+    /// ```
+    pub fn var_axis<const N: usize>(&self, axis: usize, ddof: usize) -> Tensor<f64, N> {
+        self.welford_axis(axis, |_, m2, n| variance(m2, n, ddof))
+    }
+
+    /// Returns the standard deviation of each lane along `axis` with `ddof` delta degrees of
+    /// freedom, as `Tensor<f64, N>` with the dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    pub fn std_axis<const N: usize>(&self, axis: usize, ddof: usize) -> Tensor<f64, N> {
+        self.welford_axis(axis, |_, m2, n| variance(m2, n, ddof).sqrt())
+    }
+
+    /// Computes the mean and the sum of squared deviations of each lane along `axis`, and
+    /// returns the results of `f` applied to them and the length of the lanes.
+    fn welford_axis<const N: usize, F>(&self, axis: usize, f: F) -> Tensor<f64, N>
+    where
+        F: Fn(f64, f64, usize) -> f64,
+    {
+        assert_valid_axis::<R>(axis);
+        assert_reduced_rank::<R, N>();
+
+        let metadata = TensorMetadata::new(self.metadata.remove_axis(axis));
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+        let a = &self.data;
+
+        Tensor::make_with(metadata, |j| {
+            let (o, i) = (j / inner, j % inner);
+            let (mean, m2) = unsafe { welford(len, inner, a, o * len * inner + i) };
+            f(mean, m2, len)
+        })
+    }
+}
+
+impl<T> Tensor<T, 2>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the covariance matrix of the variables, for a tensor with one observation per row
+    /// and one variable per column.
+    ///
+    /// The result is a symmetric `Tensor<f64, 2>` with one row and one column per variable. The
+    /// divisor is `n - ddof` where `n` is the number of observations, and the values are NaN if
+    /// `n` is not greater than `ddof`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// // 3 observations of 2 variables.
+    /// let observations = Tensor::from_slice([3, 2], &[1.0, 6.0, 2.0, 4.0, 3.0, 2.0]);
+    ///
+    /// let cov = observations.cov(1);
+    ///
+    /// assert_eq!(cov.shape(), &[2, 2]);
+    /// assert_eq!(cov.as_slice(), &[1.0, -2.0, -2.0, 4.0]);
+    /// ```
+    pub fn cov(&self, ddof: usize) -> Tensor<f64, 2> {
+        let [n, _] = self.metadata.dims();
+
+        self.comoments(|m2, _, _| variance(m2, n, ddof))
+    }
+
+    /// Returns the Pearson correlation coefficients of the variables, for a tensor with one
+    /// observation per row and one variable per column.
+    ///
+    /// The result is a symmetric `Tensor<f64, 2>` with one row and one column per variable. The
+    /// coefficients are NaN for variables with zero variance, as for `pearson_correlation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let observations = Tensor::from_slice([3, 2], &[1, 6, 2, 4, 3, 2]);
+    ///
+    /// let corr = observations.corrcoef();
+    ///
+    /// assert_eq!(corr.as_slice(), &[1.0, -1.0, -1.0, 1.0]);
+    /// ```
+    pub fn corrcoef(&self) -> Tensor<f64, 2> {
+        self.comoments(|m2, m2_x, m2_y| {
+            let r = m2 / (m2_x * m2_y).sqrt();
+            // Rounding errors may push the coefficient slightly out of [-1, 1].
+            r.clamp(-1.0, 1.0)
+        })
+    }
+
+    /// Computes the sums of products of deviations from the means between each pair of columns,
+    /// and returns the results of `f` applied to them and the sums of squared deviations of the
+    /// two columns. The sums are accumulated with the global summation strategy.
+    fn comoments<F>(&self, f: F) -> Tensor<f64, 2>
+    where
+        F: Fn(f64, f64, f64) -> f64,
+    {
+        let [n, m] = self.metadata.dims();
+        let a = &self.data;
+
+        let means: Tensor<f64, 1> = self.mean_axis(0);
+        let summation = Summation::global();
+        let mean = &means.data;
+
+        // Two passes over the centered values are numerically stable.
+        let m2 = Tensor::make_with(TensorMetadata::new([m, m]), |j| {
+            let (x, y) = (j / m, j % m);
+
+            let mut sum = Accumulator::new(summation);
+            let mut k = 0;
+            while k < n {
+                unsafe {
+                    let a_x: f64 = (*a.reference(k * m + x)).into();
+                    let a_y: f64 = (*a.reference(k * m + y)).into();
+                    sum.add((a_x - *mean.reference(x)) * (a_y - *mean.reference(y)));
+                }
+                k += 1;
+            }
+            sum.sum()
+        });
+
+        let c = &m2.data;
+
+        Tensor::make_with(m2.metadata, |j| {
+            let (x, y) = (j / m, j % m);
+            unsafe {
+                f(
+                    *c.reference(j),
+                    *c.reference(x * m + x),
+                    *c.reference(y * m + y),
+                )
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod moments_tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_var_std() {
+        let tensor = Tensor::from_slice([2, 4], &[2u8, 4, 4, 4, 5, 5, 7, 9]);
+
+        assert_eq!(tensor.mean(), 5.0);
+        assert!((tensor.var(0) - 4.0).abs() < 1e-12);
+        assert!((tensor.std(0) - 2.0).abs() < 1e-12);
+        assert!((tensor.var(1) - 32.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_var_ddof_too_large() {
+        let tensor = Tensor::from_slice([1], &[3.0]);
+
+        assert_eq!(tensor.var(0), 0.0);
+        assert!(tensor.var(1).is_nan());
+    }
+
+    #[test]
+    fn test_var_large_offset() {
+        // The naive formula E[x^2] - E[x]^2 loses all precision for these values.
+        let tensor = Tensor::from_slice([4], &[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+
+        assert!((tensor.var(1) - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_axis() {
+        let tensor = Tensor::from_slice([2, 3], &[1.0, 2.0, 3.0, 3.0, 6.0, 9.0]);
+
+        let mean: Tensor<f64, 1> = tensor.mean_axis(0);
+        let var: Tensor<f64, 1> = tensor.var_axis(0, 0);
+        let std: Tensor<f64, 1> = tensor.std_axis(1, 1);
+
+        assert_eq!(mean.as_slice(), &[2.0, 4.0, 6.0]);
+        assert_eq!(var.as_slice(), &[1.0, 4.0, 9.0]);
+        assert_eq!(std.as_slice(), &[1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_cov() {
+        let observations = Tensor::from_slice([4, 3], &[1, 2, 0, 2, 4, 0, 3, 6, 0, 4, 8, 0]);
+
+        let cov = observations.cov(0);
+
+        assert_eq!(cov.shape(), &[3, 3]);
+        assert_eq!(
+            cov.as_slice(),
+            &[1.25, 2.5, 0.0, 2.5, 5.0, 0.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_corrcoef() {
+        let observations = Tensor::from_slice(
+            [4, 3],
+            &[1.0, 2.0, 1.0, 2.0, 1.0, 1.0, 3.0, 4.0, 1.0, 4.0, 3.0, 1.0],
+        );
+
+        let corr = observations.corrcoef();
+
+        assert_eq!(corr.get(&[0, 0]), &1.0);
+        assert!((corr.get(&[0, 1]) - 0.6).abs() < 1e-12);
+        assert_eq!(corr.get(&[0, 1]), corr.get(&[1, 0]));
+        assert!(corr.get(&[2, 2]).is_nan());
+    }
+}
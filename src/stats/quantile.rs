@@ -0,0 +1,282 @@
+use crate::Tensor;
+use crate::assertions::{assert_reduced_rank, assert_valid_axis};
+use crate::metadata::TensorMetadata;
+use crate::num::TotalOrder;
+
+/// Method to compute a quantile that lies between two data points `lower` and `higher`, at
+/// fractional position `fraction` between them.
+/// The following methods are defined:
+/// - `Linear`: `lower + (higher - lower) * fraction`.
+/// - `Lower`: `lower`.
+/// - `Higher`: `higher`.
+/// - `Nearest`: `lower` or `higher`, whichever is nearest. When `fraction` is `0.5`, the data
+///   point with even position is selected.
+/// - `Midpoint`: `(lower + higher) / 2`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Interpolation {
+    #[default]
+    Linear,
+    Lower,
+    Higher,
+    Nearest,
+    Midpoint,
+}
+
+/// Asserts that the quantile `q` is in the interval `[0, 1]`.
+#[inline(always)]
+fn assert_valid_quantile(q: f64) {
+    assert!((0.0..=1.0).contains(&q), "Quantile out of bounds");
+}
+
+/// Returns the quantile `q` of `values`, which are reordered.
+///
+/// NaN is returned if any of the values is NaN.
+fn quantile_of(values: &mut [f64], q: f64, interpolation: Interpolation) -> f64 {
+    let n = values.len();
+
+    // NaN of either sign propagates, whichever end of the order it sorts to.
+    if values.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+
+    let position = q * (n - 1) as f64;
+    let k = position.floor() as usize;
+    let fraction = position - k as f64;
+
+    let (_, lower, rest) = values.select_nth_unstable_by(k, f64::total_order);
+    let lower = *lower;
+
+    // The value following `lower` in order is the least value of the remaining values.
+    let higher = match rest.iter().copied().min_by(f64::total_order) {
+        Some(higher) => higher,
+        None => return lower,
+    };
+
+    match interpolation {
+        // The difference of infinities is NaN, even when `higher` has no weight.
+        Interpolation::Linear if fraction == 0.0 || lower == higher => lower,
+        Interpolation::Linear => lower + (higher - lower) * fraction,
+        Interpolation::Lower => lower,
+        Interpolation::Higher if fraction > 0.0 => higher,
+        Interpolation::Higher => lower,
+        Interpolation::Nearest if fraction < 0.5 || (fraction == 0.5 && k.is_multiple_of(2)) => {
+            lower
+        }
+        Interpolation::Nearest => higher,
+        Interpolation::Midpoint if fraction > 0.0 => (lower + higher) / 2.0,
+        Interpolation::Midpoint => lower,
+    }
+}
+
+impl<T, const R: usize> Tensor<T, R>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the median of the elements, the quantile `0.5` with `Linear` interpolation.
+    ///
+    /// NaN is returned if any element is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::Tensor;
+    ///
+    /// let tensor = Tensor::from_slice([2, 2], &[4, 1, 3, 8]);
+    ///
+    /// assert_eq!(tensor.median(), 3.5);
+    /// ```
+    pub fn median(&self) -> f64 {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// Returns the quantile `q` of the elements, computed with `interpolation` when it lies
+    /// between two elements.
+    ///
+    /// NaN is returned if any element is NaN.
+    ///
+    /// # Panics
+    /// This method will panic if `q` is not in the interval `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{Interpolation, Tensor};
+    ///
+    /// let tensor = Tensor::from_slice([5], &[1, 2, 3, 4, 10]);
+    ///
+    /// assert_eq!(tensor.quantile(0.875, Interpolation::Linear), 7.0);
+    /// assert_eq!(tensor.quantile(0.875, Interpolation::Lower), 4.0);
+    /// assert_eq!(tensor.quantile(0.875, Interpolation::Nearest), 10.0);
+    /// ```
+    pub fn quantile(&self, q: f64, interpolation: Interpolation) -> f64 {
+        assert_valid_quantile(q);
+
+        let mut values: Vec<f64> = self.as_slice().iter().map(|&x| x.into()).collect();
+        quantile_of(&mut values, q, interpolation)
+    }
+
+    /// Returns the percentile `p` of the elements, the quantile `p / 100`.
+    ///
+    /// # Panics
+    /// This method will panic if `p` is not in the interval `[0, 100]`.
+    pub fn percentile(&self, p: f64, interpolation: Interpolation) -> f64 {
+        self.quantile(p / 100.0, interpolation)
+    }
+
+    /// Returns the median of each lane along `axis`, as `Tensor<f64, N>` with the dimension of
+    /// `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, or if `N` is not `R - 1`.
+    pub fn median_axis<const N: usize>(&self, axis: usize) -> Tensor<f64, N> {
+        self.quantile_axis(axis, 0.5, Interpolation::Linear)
+    }
+
+    /// Returns the quantile `q` of each lane along `axis`, as `Tensor<f64, N>` with the dimension
+    /// of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, if `N` is not `R - 1`, or if `q` is
+    /// not in the interval `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tensor::{Interpolation, Tensor};
+    ///
+    /// let tensor = Tensor::from_slice([2, 4], &[1, 2, 3, 4, 8, 6, 4, 2]);
+    ///
+    /// let rows: Tensor<f64, 1> = tensor.quantile_axis(1, 0.5, Interpolation::Midpoint);
+    ///
+    /// assert_eq!(rows.as_slice(), &[2.5, 5.0]);
+    /// ```
+    pub fn quantile_axis<const N: usize>(
+        &self,
+        axis: usize,
+        q: f64,
+        interpolation: Interpolation,
+    ) -> Tensor<f64, N> {
+        assert_valid_axis::<R>(axis);
+        assert_reduced_rank::<R, N>();
+        assert_valid_quantile(q);
+
+        let metadata = TensorMetadata::new(self.metadata.remove_axis(axis));
+        let (_, len, inner) = self.metadata.split_at_axis(axis);
+        let a = &self.data;
+
+        let mut lane: Vec<f64> = Vec::with_capacity(len);
+
+        Tensor::make_with(metadata, |j| {
+            let (o, i) = (j / inner, j % inner);
+            let base = o * len * inner + i;
+
+            lane.clear();
+            let mut k = 0;
+            while k < len {
+                lane.push(unsafe { (*a.reference(base + k * inner)).into() });
+                k += 1;
+            }
+
+            quantile_of(&mut lane, q, interpolation)
+        })
+    }
+
+    /// Returns the percentile `p` of each lane along `axis`, as `Tensor<f64, N>` with the
+    /// dimension of `axis` removed.
+    ///
+    /// # Panics
+    /// This method will panic if `axis` is not less than `R`, if `N` is not `R - 1`, or if `p` is
+    /// not in the interval `[0, 100]`.
+    pub fn percentile_axis<const N: usize>(
+        &self,
+        axis: usize,
+        p: f64,
+        interpolation: Interpolation,
+    ) -> Tensor<f64, N> {
+        self.quantile_axis(axis, p / 100.0, interpolation)
+    }
+}
+
+#[cfg(test)]
+mod quantile_tests {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        let odd = Tensor::from_slice([5], &[5.0, 1.0, 4.0, 2.0, 3.0]);
+        let even = Tensor::from_slice([2, 3], &[6u32, 1, 5, 2, 4, 3]);
+
+        assert_eq!(odd.median(), 3.0);
+        assert_eq!(even.median(), 3.5);
+    }
+
+    #[test]
+    fn test_median_nan() {
+        let tensor = Tensor::from_slice([3], &[1.0, f64::NAN, 2.0]);
+
+        assert!(tensor.median().is_nan());
+    }
+
+    #[test]
+    fn test_quantile_interpolations() {
+        // Sorted: 10, 20, 30, 40, the quantile 0.5 lies at position 1.5.
+        let tensor = Tensor::from_slice([4], &[40, 10, 30, 20]);
+
+        assert_eq!(tensor.quantile(0.5, Interpolation::Linear), 25.0);
+        assert_eq!(tensor.quantile(0.5, Interpolation::Lower), 20.0);
+        assert_eq!(tensor.quantile(0.5, Interpolation::Higher), 30.0);
+        assert_eq!(tensor.quantile(0.5, Interpolation::Nearest), 30.0);
+        assert_eq!(tensor.quantile(0.5, Interpolation::Midpoint), 25.0);
+
+        // Ties between positions are resolved to the even position.
+        let tensor = Tensor::from_slice([5], &[10, 20, 30, 40, 50]);
+
+        assert_eq!(tensor.quantile(0.125, Interpolation::Nearest), 10.0);
+        assert_eq!(tensor.quantile(0.375, Interpolation::Nearest), 30.0);
+    }
+
+    #[test]
+    fn test_quantile_infinities() {
+        let tensor = Tensor::from_slice([3], &[1.0, f64::INFINITY, f64::INFINITY]);
+
+        assert_eq!(tensor.median(), f64::INFINITY);
+        assert_eq!(tensor.quantile(0.75, Interpolation::Linear), f64::INFINITY);
+
+        let tensor = Tensor::from_slice([3], &[f64::NEG_INFINITY, 1.0, 2.0]);
+
+        assert_eq!(
+            tensor.quantile(0.0, Interpolation::Linear),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(tensor.quantile(0.75, Interpolation::Linear), 1.5);
+    }
+
+    #[test]
+    fn test_quantile_bounds() {
+        let tensor = Tensor::from_slice([3], &[3, 1, 2]);
+
+        assert_eq!(tensor.quantile(0.0, Interpolation::Higher), 1.0);
+        assert_eq!(tensor.quantile(1.0, Interpolation::Lower), 3.0);
+        assert_eq!(tensor.percentile(50.0, Interpolation::Linear), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quantile out of bounds")]
+    fn test_percentile_out_of_bounds() {
+        let tensor = Tensor::from_slice([3], &[3, 1, 2]);
+
+        let _ = tensor.percentile(101.0, Interpolation::Linear);
+    }
+
+    #[test]
+    fn test_quantile_axis() {
+        let tensor = Tensor::from_slice([3, 2], &[1.0, 6.0, 3.0, 4.0, 2.0, f64::NAN]);
+
+        let median: Tensor<f64, 1> = tensor.median_axis(0);
+        let p25: Tensor<f64, 1> = tensor.percentile_axis(0, 25.0, Interpolation::Linear);
+
+        assert_eq!(median.as_slice()[0], 2.0);
+        assert!(median.as_slice()[1].is_nan());
+        assert_eq!(p25.as_slice()[0], 1.5);
+    }
+}